@@ -0,0 +1,100 @@
+use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use std::sync::Arc;
+use std::time::Duration;
+
+struct Chars;
+
+impl<'a> BorrowWithLifetime<'a> for Chars {
+    type Borrowed = std::str::Chars<'a>;
+}
+
+type Halves = (&'static str, &'static str);
+
+const TEXT: &str = "the quick brown fox jumps over the lazy dog";
+
+fn owner() -> Arc<str> {
+    Arc::from(TEXT)
+}
+
+fn bench_new(c: &mut Criterion) {
+    c.bench_function("new", |b| {
+        b.iter_batched(owner, RefWithOwner::new, BatchSize::SmallInput)
+    });
+}
+
+fn bench_map(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map");
+    group.bench_function("reference", |b| {
+        b.iter_batched(
+            || RefWithOwner::new(owner()),
+            |s| s.map::<&'static str, _>(|s, _| &s[4..]),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("iterator", |b| {
+        b.iter_batched(
+            || RefWithOwner::new(owner()),
+            |s| s.map::<Chars, _>(|s, _| s.chars()),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("tuple", |b| {
+        b.iter_batched(
+            || RefWithOwner::new(owner()),
+            |s| s.map::<Halves, _>(|s, _| s.split_at(s.len() / 2)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_borrowed(c: &mut Criterion) {
+    let mut group = c.benchmark_group("borrowed");
+    let reference = RefWithOwner::new(owner());
+    group.bench_function("reference", |b| {
+        b.iter(|| black_box(&reference).borrowed().len())
+    });
+    let tuple = RefWithOwner::new(owner()).map::<Halves, _>(|s, _| s.split_at(s.len() / 2));
+    group.bench_function("tuple", |b| b.iter(|| black_box(&tuple).borrowed().1.len()));
+    group.finish();
+}
+
+fn bench_borrowed_mut(c: &mut Criterion) {
+    c.bench_function("borrowed_mut/iterator", |b| {
+        b.iter_batched(
+            || RefWithOwner::new(owner()).map::<Chars, _>(|s, _| s.chars()),
+            |mut chars| chars.borrowed_mut().filter(|c| *c == 'o').count(),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone");
+    let reference = RefWithOwner::new(owner());
+    group.bench_function("reference", |b| b.iter(|| black_box(&reference).clone()));
+    let chars = RefWithOwner::new(owner()).map::<Chars, _>(|s, _| s.chars());
+    group.bench_function("iterator", |b| b.iter(|| black_box(&chars).clone()));
+    group.finish();
+}
+
+fn bench_into_owner(c: &mut Criterion) {
+    c.bench_function("into_owner/iterator", |b| {
+        b.iter_batched(
+            || RefWithOwner::new(owner()).map::<Chars, _>(|s, _| s.chars()),
+            |chars| chars.into_owner(),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group! {
+    name = benches;
+    // keep each benchmark well under a second
+    config = Criterion::default()
+        .warm_up_time(Duration::from_millis(100))
+        .measurement_time(Duration::from_millis(300));
+    targets = bench_new, bench_map, bench_borrowed, bench_borrowed_mut, bench_clone, bench_into_owner
+}
+criterion_main!(benches);
@@ -0,0 +1,88 @@
+//! `#[derive(BorrowWithLifetime)]` for `borrowed_with_owner`. Use it through the `derive` feature
+//! of `borrowed_with_owner`, which re-exports it.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, Error, GenericParam};
+
+/// Implements `BorrowWithLifetime` for a struct or enum with exactly one lifetime parameter,
+/// following the convention of using the type with a `'static` lifetime as the marker:
+///
+/// ```
+/// use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+///
+/// #[derive(BorrowWithLifetime)]
+/// struct Words<'a>(Vec<&'a str>);
+///
+/// let words = RefWithOwner::new(String::from("a b")).map::<Words<'static>, _>(|s, _| {
+///     Words(s.split(' ').collect())
+/// });
+/// assert_eq!(words.borrowed().0, ["a", "b"]);
+/// ```
+///
+/// Const parameters are allowed, but type parameters aren't, since the impl would need to know
+/// how the borrowed type's lifetime applies to them.
+#[proc_macro_derive(BorrowWithLifetime)]
+pub fn derive_borrow_with_lifetime(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+
+    let mut lifetimes = input.generics.lifetimes();
+    let lifetime = match (lifetimes.next(), lifetimes.next()) {
+        (Some(lifetime), None) => &lifetime.lifetime,
+        (None, _) => {
+            return Err(Error::new_spanned(
+                name,
+                "`BorrowWithLifetime` can only be derived for types with a lifetime parameter",
+            ))
+        }
+        (Some(_), Some(second)) => {
+            return Err(Error::new_spanned(
+                second,
+                "`BorrowWithLifetime` can only be derived for types with exactly one lifetime parameter",
+            ))
+        }
+    };
+
+    if let Some(param) = input.generics.type_params().next() {
+        return Err(Error::new_spanned(
+            param,
+            "`BorrowWithLifetime` can't be derived for types with type parameters; implement it by hand instead",
+        ));
+    }
+
+    // defaults aren't allowed on impl parameters, so only the name and type are kept
+    let const_params = input.generics.const_params().map(|param| {
+        let ident = &param.ident;
+        let ty = &param.ty;
+        quote!(const #ident: #ty)
+    });
+    let args = |lifetime_arg: proc_macro2::TokenStream| {
+        let args = input.generics.params.iter().map(|param| match param {
+            GenericParam::Lifetime(_) => lifetime_arg.clone(),
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+            GenericParam::Type(_) => unreachable!(),
+        });
+        quote!(<#(#args),*>)
+    };
+    let static_args = args(quote!('static));
+    let borrowed_args = args(quote!(#lifetime));
+    let where_clause = &input.generics.where_clause;
+
+    Ok(quote! {
+        impl<#lifetime, #(#const_params),*> ::borrowed_with_owner::BorrowWithLifetime<#lifetime>
+            for #name #static_args #where_clause
+        {
+            type Borrowed = #name #borrowed_args;
+        }
+    })
+}
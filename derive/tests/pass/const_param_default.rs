@@ -0,0 +1,10 @@
+use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+
+#[derive(BorrowWithLifetime)]
+struct Fixed<'a, const N: usize = 4>(&'a [u8; N]);
+
+fn main() {
+    let fixed = RefWithOwner::new(Box::new([1u8, 2, 3, 4]))
+        .map::<Fixed<'static>, _>(|bytes, _| Fixed(bytes));
+    assert_eq!(fixed.borrowed().0, &[1, 2, 3, 4]);
+}
@@ -0,0 +1,30 @@
+use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+
+#[derive(BorrowWithLifetime)]
+struct Split<'s>(std::str::Split<'s, char>);
+
+#[derive(BorrowWithLifetime)]
+enum Token<'t> {
+    Word(&'t str),
+    Number(u32),
+}
+
+#[derive(BorrowWithLifetime)]
+struct Chunk<'a, const N: usize> {
+    bytes: &'a [u8; N],
+}
+
+fn main() {
+    let mut split = RefWithOwner::new(String::from("a,b"))
+        .map::<Split<'static>, _>(|s, _| Split(s.split(',')));
+    assert_eq!(split.borrowed_mut().0.next(), Some("a"));
+
+    let token = RefWithOwner::new(String::from("word"))
+        .map::<Token<'static>, _>(|s, _| Token::Word(s));
+    assert!(matches!(token.borrowed(), Token::Word("word")));
+    let _ = Token::Number(1);
+
+    let chunk = RefWithOwner::new(Box::new([1u8, 2]))
+        .map::<Chunk<'static, 2>, _>(|bytes, _| Chunk { bytes });
+    assert_eq!(chunk.borrowed().bytes, &[1, 2]);
+}
@@ -0,0 +1,6 @@
+use borrowed_with_owner::BorrowWithLifetime;
+
+#[derive(BorrowWithLifetime)]
+struct Owned(String);
+
+fn main() {}
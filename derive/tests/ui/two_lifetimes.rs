@@ -0,0 +1,6 @@
+use borrowed_with_owner::BorrowWithLifetime;
+
+#[derive(BorrowWithLifetime)]
+struct Pair<'a, 'b>(&'a str, &'b str);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use borrowed_with_owner::BorrowWithLifetime;
+
+#[derive(BorrowWithLifetime)]
+struct Slice<'a, T>(&'a [T]);
+
+fn main() {}
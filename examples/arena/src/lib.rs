@@ -0,0 +1,113 @@
+//! Bundles over buffers carved out of a static arena, for `no_std` targets without a heap. The
+//! crate only builds against `core` (plus `alloc`, which `borrowed_with_owner` is built with but
+//! this example never allocates from).
+
+#![cfg_attr(not(test), no_std)]
+
+use borrowed_with_owner::{BorrowedWithOwner, RefWithOwner};
+use core::cell::UnsafeCell;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use stable_deref_trait::StableDeref;
+
+/// A bump allocator over a fixed-size buffer, meant to be stored in a `static`. Buffers are
+/// never freed.
+pub struct Arena<const N: usize> {
+    buffer: UnsafeCell<[u8; N]>,
+    used: AtomicUsize,
+}
+
+// each allocation hands out a disjoint part of `buffer`
+unsafe impl<const N: usize> Sync for Arena<N> {}
+
+impl<const N: usize> Arena<N> {
+    pub const fn new() -> Self {
+        Self {
+            buffer: UnsafeCell::new([0; N]),
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    /// Copies `bytes` into a new buffer in the arena, or returns `None` if it's full
+    pub fn alloc(&'static self, bytes: &[u8]) -> Option<ArenaBox> {
+        let start = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(bytes.len()).filter(|&end| end <= N)
+            })
+            .ok()?;
+
+        // `start..start + bytes.len()` was reserved above, so nothing else can access it
+        let buffer = unsafe {
+            let ptr = (self.buffer.get() as *mut u8).add(start);
+            core::slice::from_raw_parts_mut(ptr, bytes.len())
+        };
+        buffer.copy_from_slice(bytes);
+
+        Some(ArenaBox(buffer))
+    }
+}
+
+impl<const N: usize> Default for Arena<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A buffer allocated in an `Arena`. It owns its bytes exclusively, and since they live in a
+/// `static`, they don't move when the `ArenaBox` does.
+pub struct ArenaBox(&'static mut [u8]);
+
+impl Deref for ArenaBox {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl DerefMut for ArenaBox {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.0
+    }
+}
+
+unsafe impl StableDeref for ArenaBox {}
+
+/// A length-prefixed packet bundled with the arena buffer it was read from
+pub type Packet = BorrowedWithOwner<&'static [u8], ArenaBox>;
+
+/// Copies `bytes` into `arena` and returns a bundle of the packet's payload, i.e. the number of
+/// bytes given by its first byte. Returns `None` if the arena is full or the packet is truncated.
+pub fn read_packet<const N: usize>(arena: &'static Arena<N>, bytes: &[u8]) -> Option<Packet> {
+    let len = usize::from(*bytes.first()?);
+    if bytes.len() <= len {
+        return None;
+    }
+
+    let buffer = arena.alloc(bytes)?;
+    Some(RefWithOwner::new(buffer).map(|bytes, _| &bytes[1..=len]))
+}
+
+#[test]
+fn test_read_packet() {
+    static ARENA: Arena<8> = Arena::new();
+
+    let first = read_packet(&ARENA, &[2, b'h', b'i', 0xff]).unwrap();
+    let second = read_packet(&ARENA, &[3, b'y', b'o', b'u']).unwrap();
+    assert_eq!(*first, *b"hi");
+    assert_eq!(*second, *b"you");
+
+    // the arena is full
+    assert!(read_packet(&ARENA, &[0]).is_none());
+
+    static OTHER: Arena<8> = Arena::new();
+    // truncated
+    assert!(read_packet(&OTHER, &[5, 1]).is_none());
+    assert!(read_packet(&OTHER, &[0]).unwrap().is_empty());
+
+    let packet = std::thread::spawn(move || first.into_owner())
+        .join()
+        .unwrap();
+    assert_eq!(*packet, [2, b'h', b'i', 0xff]);
+}
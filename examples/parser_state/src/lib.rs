@@ -0,0 +1,78 @@
+//! An incremental parser for whitespace-separated integers, whose state borrows the input it is
+//! parsing and can be bundled up with it.
+
+use borrowed_with_owner::{BorrowWithLifetime, BorrowedWithOwner, RefWithOwner};
+use std::num::ParseIntError;
+
+pub struct ParserState<'a> {
+    remaining: &'a str,
+    output: Vec<i64>,
+}
+
+impl<'a> BorrowWithLifetime<'a> for ParserState<'static> {
+    type Borrowed = ParserState<'a>;
+}
+
+impl<'a> ParserState<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            remaining: input,
+            output: Vec::new(),
+        }
+    }
+
+    /// Parses the next integer, returning `Ok(false)` once the input is exhausted
+    pub fn step(&mut self) -> Result<bool, ParseIntError> {
+        let trimmed = self.remaining.trim_start();
+        if trimmed.is_empty() {
+            self.remaining = trimmed;
+            return Ok(false);
+        }
+
+        let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let (token, rest) = trimmed.split_at(end);
+        self.output.push(token.parse()?);
+        self.remaining = rest;
+        Ok(true)
+    }
+
+    pub fn remaining(&self) -> &'a str {
+        self.remaining
+    }
+
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+}
+
+/// A `ParserState` bundled with the input that it is parsing
+pub type Parser = BorrowedWithOwner<ParserState<'static>, String>;
+
+pub fn parser(input: String) -> Parser {
+    RefWithOwner::new(input).map(|input, _| ParserState::new(input))
+}
+
+#[test]
+fn test_parser_state() {
+    let mut parser = parser("1 2  -3\n40".into());
+
+    let output = std::thread::spawn(move || {
+        while parser.borrowed_mut().step().unwrap() {}
+        assert_eq!(parser.borrowed().remaining(), "");
+        parser.borrowed().output().to_vec()
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(output, [1, 2, -3, 40]);
+}
+
+#[test]
+fn test_parser_state_error() {
+    let mut parser = parser("1 x 3".into());
+
+    assert_eq!(parser.borrowed_mut().step(), Ok(true));
+    assert!(parser.borrowed_mut().step().is_err());
+    assert_eq!(parser.borrowed().remaining(), " x 3");
+    assert_eq!(parser.borrowed().output(), [1]);
+}
@@ -0,0 +1,88 @@
+//! A tokenizer that yields tokens borrowing from its source, bundled up with the source so it
+//! can be sent to another thread.
+
+use borrowed_with_owner::{BorrowWithLifetime, BorrowedWithOwner, RefWithOwner};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    Ident(&'a str),
+    Number(&'a str),
+    Punct(char),
+}
+
+pub struct Tokenizer<'a> {
+    remaining: &'a str,
+}
+
+impl<'a> BorrowWithLifetime<'a> for Tokenizer<'static> {
+    type Borrowed = Tokenizer<'a>;
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self { remaining: source }
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let end = self
+            .remaining
+            .find(|c| !f(c))
+            .unwrap_or(self.remaining.len());
+        let (taken, rest) = self.remaining.split_at(end);
+        self.remaining = rest;
+        taken
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        self.remaining = self.remaining.trim_start();
+        let c = self.remaining.chars().next()?;
+
+        let token = if c.is_alphabetic() || c == '_' {
+            Token::Ident(self.take_while(|c| c.is_alphanumeric() || c == '_'))
+        } else if c.is_ascii_digit() {
+            Token::Number(self.take_while(|c| c.is_ascii_digit()))
+        } else {
+            self.remaining = &self.remaining[c.len_utf8()..];
+            Token::Punct(c)
+        };
+
+        Some(token)
+    }
+}
+
+/// A `Tokenizer` bundled with its source
+pub type OwnedTokenizer = BorrowedWithOwner<Tokenizer<'static>, String>;
+
+/// Tokenizes the first line of `source`
+pub fn tokenize_first_line(source: String) -> OwnedTokenizer {
+    RefWithOwner::new(source)
+        .map::<&'static str, _>(|source, _| source.lines().next().unwrap_or(""))
+        .map(|line, _| Tokenizer::new(line))
+}
+
+#[test]
+fn test_tokenizer() {
+    let mut tokenizer = tokenize_first_line("let x_1 = 42 + y;\nignored".into());
+
+    std::thread::spawn(move || {
+        let tokens = tokenizer.borrowed_mut().collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                Token::Ident("let"),
+                Token::Ident("x_1"),
+                Token::Punct('='),
+                Token::Number("42"),
+                Token::Punct('+'),
+                Token::Ident("y"),
+                Token::Punct(';'),
+            ]
+        );
+    })
+    .join()
+    .unwrap();
+}
@@ -0,0 +1,17 @@
+use crate::RefWithOwner;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use stable_deref_trait::StableDeref;
+
+/// Generates an arbitrary owner, and borrows its target
+impl<'a, O> Arbitrary<'a> for RefWithOwner<O>
+where
+    O: Arbitrary<'a> + StableDeref,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        O::arbitrary(u).map(RefWithOwner::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        O::size_hint(depth)
+    }
+}
@@ -0,0 +1,92 @@
+//! A bundled stream, e.g. one built with `async_stream::stream!`, that borrows from its owner
+
+use crate::{BorrowWithLifetime, BorrowedWithOwner, Lifetime};
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+use stable_deref_trait::StableDeref;
+
+/// Marker for a boxed stream, which may borrow from the owner, that yields `T`s. The stream has
+/// to be `Send`, like `BoxFuture`; use `LocalBoxStream` for streams that aren't.
+pub struct BoxStream<T>(PhantomData<T>);
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for BoxStream<T> {
+    type Borrowed = Pin<Box<dyn Stream<Item = T> + Send + 'a>>;
+}
+
+/// Same as `BoxStream`, but the stream doesn't have to be `Send`
+pub struct LocalBoxStream<T>(PhantomData<T>);
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for LocalBoxStream<T> {
+    type Borrowed = Pin<Box<dyn Stream<Item = T> + 'a>>;
+}
+
+impl<B, O> BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: StableDeref,
+{
+    /// Calls `f` with the borrowed value, and stores the stream that it returns in a new
+    /// `BorrowedWithOwner`, which keeps the owner alive for as long as the stream runs. The
+    /// items have to be `'static`, so they can be yielded from the bundle.
+    ///
+    /// ```
+    /// use async_stream::stream;
+    /// use borrowed_with_owner::RefWithOwner;
+    ///
+    /// let lines = RefWithOwner::new(String::from("a\nb")).map_stream(|s, _| {
+    ///     Box::pin(stream! {
+    ///         for line in s.lines() {
+    ///             yield line.to_owned();
+    ///         }
+    ///     })
+    /// });
+    /// ```
+    pub fn map_stream<T, F>(self, f: F) -> BorrowedWithOwner<BoxStream<T>, O>
+    where
+        T: 'static,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> Pin<Box<dyn Stream<Item = T> + Send + 'a>>,
+    {
+        self.map::<BoxStream<T>, _>(f)
+    }
+
+    /// Same as `map_stream`, but for streams that aren't `Send`
+    pub fn map_local_stream<T, F>(self, f: F) -> BorrowedWithOwner<LocalBoxStream<T>, O>
+    where
+        T: 'static,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> Pin<Box<dyn Stream<Item = T> + 'a>>,
+    {
+        self.map::<LocalBoxStream<T>, _>(f)
+    }
+}
+
+macro_rules! stream_impls {
+    ($($marker:ident)+) => {
+        $(
+            impl<T, O> Stream for BorrowedWithOwner<$marker<T>, O>
+            where
+                T: 'static,
+                O: StableDeref + Unpin,
+            {
+                type Item = T;
+
+                fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+                    self.get_mut().borrowed_mut().as_mut().poll_next(cx)
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    self.borrowed().size_hint()
+                }
+            }
+        )+
+    };
+}
+
+stream_impls!(BoxStream LocalBoxStream);
@@ -0,0 +1,49 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner, Lifetime};
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+use stable_deref_trait::StableDeref;
+
+/// Marker for a boxed iterator, which may borrow from the owner, whose items are described by the
+/// marker `T`. For example, `BoxedIter<&'static i32>` is a `Box<dyn Iterator<Item = &'a i32> + 'a>`.
+/// This lets you store an iterator whose type can't be named, such as one built from
+/// `iter().filter(..)` with a closure.
+pub struct BoxedIter<T>(PhantomData<T>);
+
+impl<'a, T> BorrowWithLifetime<'a> for BoxedIter<T>
+where
+    T: BorrowWithLifetime<'a> + 'static,
+{
+    type Borrowed = Box<dyn Iterator<Item = <T as BorrowWithLifetime<'a>>::Borrowed> + 'a>;
+}
+
+impl<B, O> BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: StableDeref,
+{
+    /// Calls `f` with the borrowed value, and stores the boxed iterator that it returns in a new
+    /// `BorrowedWithOwner`. The iterator's items are references into the owner, so they can be
+    /// handed out for as long as the bundle is borrowed.
+    pub fn map_impl_iter<Item, F>(self, f: F) -> BorrowedWithOwner<BoxedIter<&'static Item>, O>
+    where
+        Item: ?Sized + 'static,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> Box<dyn Iterator<Item = &'a Item> + 'a>,
+    {
+        self.map::<BoxedIter<&'static Item>, _>(f)
+    }
+}
+
+impl<T, O> BorrowedWithOwner<BoxedIter<T>, O>
+where
+    T: for<'a> BorrowWithLifetime<'a> + 'static,
+    O: StableDeref,
+{
+    /// Returns the boxed iterator's size hint. The box forwards `size_hint` to the underlying
+    /// iterator, so the hint is as accurate as the iterator's own.
+    pub fn size_hint_borrowed(&self) -> (usize, Option<usize>) {
+        self.borrowed().size_hint()
+    }
+}
@@ -0,0 +1,60 @@
+use crate::BorrowWithLifetime;
+use alloc::boxed::Box;
+use core::fmt::{Debug, Display};
+use core::marker::PhantomData;
+
+/// Like `BorrowWithLifetime`, but for unsized types like `str`, `[T]`, and trait objects, which
+/// can't be stored in a bundle by value. Use it with the `BoxedUnsized` marker to store them in a
+/// `Box` instead.
+///
+/// To use a trait object for your own trait, implement this for the `dyn` type:
+///
+/// ```
+/// use borrowed_with_owner::UnsizedWithLifetime;
+///
+/// trait Shape {
+///     fn area(&self) -> f64;
+/// }
+///
+/// impl<'a> UnsizedWithLifetime<'a> for dyn Shape {
+///     type Unsized = dyn Shape + 'a;
+/// }
+/// ```
+pub trait UnsizedWithLifetime<'a> {
+    type Unsized: ?Sized + 'a;
+}
+
+/// Marker for a boxed unsized value, which may borrow from the owner. For example,
+/// `BoxedUnsized<dyn Display>` is a `Box<dyn Display + 'a>`.
+pub struct BoxedUnsized<U: ?Sized>(PhantomData<Box<U>>);
+
+impl<'a, U> BorrowWithLifetime<'a> for BoxedUnsized<U>
+where
+    U: ?Sized + UnsizedWithLifetime<'a> + 'static,
+{
+    type Borrowed = Box<<U as UnsizedWithLifetime<'a>>::Unsized>;
+}
+
+impl<'a> UnsizedWithLifetime<'a> for str {
+    type Unsized = str;
+}
+
+impl<'a, T: 'a> UnsizedWithLifetime<'a> for [T] {
+    type Unsized = [T];
+}
+
+impl<'a> UnsizedWithLifetime<'a> for dyn Display {
+    type Unsized = dyn Display + 'a;
+}
+
+impl<'a> UnsizedWithLifetime<'a> for dyn Display + Send {
+    type Unsized = dyn Display + Send + 'a;
+}
+
+impl<'a> UnsizedWithLifetime<'a> for dyn Debug {
+    type Unsized = dyn Debug + 'a;
+}
+
+impl<'a> UnsizedWithLifetime<'a> for dyn Debug + Send {
+    type Unsized = dyn Debug + Send + 'a;
+}
@@ -0,0 +1,107 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner, Lifetime};
+use core::marker::PhantomData;
+use stable_deref_trait::CloneStableDeref;
+
+/// A `BorrowedWithOwner` branded with the invariant lifetime `'id`
+///
+/// All bundles with the same brand are derived from the same original bundle by `.view()`,
+/// `.map()`, or `.zip()`, so their owners are clones of each other and deref to the same
+/// target. This is what makes it possible to `.zip()` two of them into a single bundle with a
+/// single owner. Bundles with different brands can't be zipped, because `'id` is different for
+/// every call to `BorrowedWithOwner::branded`.
+pub struct Branded<'id, B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: CloneStableDeref,
+{
+    bundle: BorrowedWithOwner<B, O>,
+    brand: PhantomData<fn(&'id ()) -> &'id ()>,
+}
+
+impl<B, O> BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: CloneStableDeref,
+{
+    /// Calls `f` with `self` branded with a unique lifetime `'id`
+    pub fn branded<R, F>(self, f: F) -> R
+    where
+        F: for<'id> FnOnce(Branded<'id, B, O>) -> R,
+    {
+        f(Branded {
+            bundle: self,
+            brand: PhantomData,
+        })
+    }
+}
+
+impl<'id, B, O> Branded<'id, B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: CloneStableDeref,
+{
+    /// Removes the brand
+    pub fn into_inner(self) -> BorrowedWithOwner<B, O> {
+        self.bundle
+    }
+
+    /// Returns an `&`-reference to the borrowed value, with lifetime tied to the borrow of `self`
+    #[allow(clippy::needless_lifetimes)]
+    pub fn borrowed<'a>(&'a self) -> &'a <B as BorrowWithLifetime<'a>>::Borrowed {
+        self.bundle.borrowed()
+    }
+
+    /// Same as `BorrowedWithOwner::map`, keeping the brand
+    pub fn map<B2, F>(self, f: F) -> Branded<'id, B2, O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        Branded {
+            bundle: self.bundle.map(f),
+            brand: PhantomData,
+        }
+    }
+
+    /// Clones `self` and maps the clone with `f`, keeping the brand
+    pub fn view<B2, F>(&self, f: F) -> Branded<'id, B2, O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Clone,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        Branded {
+            bundle: self.bundle.clone().map(f),
+            brand: PhantomData,
+        }
+    }
+
+    /// Combines the borrowed values of two bundles with the same brand into a tuple, keeping the
+    /// owner of `self`. This is sound because the owner of `other` derefs to the same target.
+    pub fn zip<B2>(self, other: Branded<'id, B2, O>) -> Branded<'id, (B, B2), O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+    {
+        let BorrowedWithOwner { borrowed, owner } = self.bundle;
+        let BorrowedWithOwner {
+            borrowed: other_borrowed,
+            owner: other_owner,
+        } = other.bundle;
+
+        let zipped = Branded {
+            bundle: BorrowedWithOwner {
+                borrowed: (borrowed, other_borrowed),
+                owner,
+            },
+            brand: PhantomData,
+        };
+        drop(other_owner);
+        zipped
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner, Lifetime};
+use bumpalo::Bump;
+use stable_deref_trait::StableDeref;
+
+impl<O> BorrowedWithOwner<&'static Bump, O>
+where
+    O: StableDeref,
+{
+    /// Calls `f` with the bundled arena, and stores the value that it returns, which can borrow
+    /// values that `f` allocates in the arena, in a new `BorrowedWithOwner`. The arena is usually
+    /// bundled with `RefWithOwner::new(Box::new(Bump::new()))`. Since `Bump` isn't `Sync`, the
+    /// resulting bundle can't be sent to another thread; use `bumpalo_herd::Herd` for that.
+    pub fn map_arena<B2, F>(self, f: F) -> BorrowedWithOwner<B2, O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(&'a Bump, Lifetime<'a>) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        self.map(f)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::BorrowedWithOwner;
+use bytemuck::TransparentWrapper;
+use stable_deref_trait::StableDeref;
+
+impl<T, O> BorrowedWithOwner<&'static T, O>
+where
+    T: ?Sized + 'static,
+    O: StableDeref,
+{
+    /// Reinterprets the borrowed `&T` as an `&U`, where `U` is a `#[repr(transparent)]` wrapper
+    /// around `T`
+    pub fn cast_ref<U>(self) -> BorrowedWithOwner<&'static U, O>
+    where
+        U: TransparentWrapper<T> + ?Sized + 'static,
+    {
+        self.map(|borrowed, _| U::wrap_ref(borrowed))
+    }
+}
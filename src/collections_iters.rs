@@ -0,0 +1,80 @@
+//! `BorrowWithLifetime` impls for iterators from `std::collections`
+
+use crate::BorrowWithLifetime;
+use alloc::collections::{btree_map, btree_set, vec_deque};
+#[cfg(feature = "std")]
+use std::collections::{hash_map, hash_set};
+
+#[cfg(feature = "std")]
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::Iter<'static, K, V> {
+    type Borrowed = hash_map::Iter<'a, K, V>;
+}
+
+#[cfg(feature = "std")]
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::IterMut<'static, K, V> {
+    type Borrowed = hash_map::IterMut<'a, K, V>;
+}
+
+#[cfg(feature = "std")]
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::Keys<'static, K, V> {
+    type Borrowed = hash_map::Keys<'a, K, V>;
+}
+
+#[cfg(feature = "std")]
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::Values<'static, K, V> {
+    type Borrowed = hash_map::Values<'a, K, V>;
+}
+
+#[cfg(feature = "std")]
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::ValuesMut<'static, K, V> {
+    type Borrowed = hash_map::ValuesMut<'a, K, V>;
+}
+
+#[cfg(feature = "std")]
+impl<'a, K: 'static> BorrowWithLifetime<'a> for hash_set::Iter<'static, K> {
+    type Borrowed = hash_set::Iter<'a, K>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for btree_map::Iter<'static, K, V> {
+    type Borrowed = btree_map::Iter<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for btree_map::IterMut<'static, K, V> {
+    type Borrowed = btree_map::IterMut<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for btree_map::Keys<'static, K, V> {
+    type Borrowed = btree_map::Keys<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for btree_map::Values<'static, K, V> {
+    type Borrowed = btree_map::Values<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for btree_map::ValuesMut<'static, K, V> {
+    type Borrowed = btree_map::ValuesMut<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for btree_map::Range<'static, K, V> {
+    type Borrowed = btree_map::Range<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for btree_map::RangeMut<'static, K, V> {
+    type Borrowed = btree_map::RangeMut<'a, K, V>;
+}
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for btree_set::Iter<'static, T> {
+    type Borrowed = btree_set::Iter<'a, T>;
+}
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for btree_set::Range<'static, T> {
+    type Borrowed = btree_set::Range<'a, T>;
+}
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for vec_deque::Iter<'static, T> {
+    type Borrowed = vec_deque::Iter<'a, T>;
+}
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for vec_deque::IterMut<'static, T> {
+    type Borrowed = vec_deque::IterMut<'a, T>;
+}
@@ -0,0 +1,84 @@
+use crate::{
+    sealed, BorrowWithLifetime, BorrowedWithOwner, Lifetime, OwnerCanBeShared, RefWithOwner,
+};
+use alloc::{boxed::Box, sync::Arc};
+use core::ops::Deref;
+use either::Either;
+use stable_deref_trait::StableDeref;
+
+/// An owner that either shares its target through an `Arc` or owns it outright, chosen at
+/// runtime. This is useful for APIs that sometimes borrow from a shared value and sometimes own
+/// their own copy, but want to return the same bundle type either way.
+#[derive(Debug)]
+pub enum CowOwner<T: ?Sized> {
+    Shared(Arc<T>),
+    Owned(Box<T>),
+}
+
+impl<T> CowOwner<T> {
+    /// Creates a `Shared` owner from `Left`, or an `Owned` one from `Right`
+    pub fn new(value: Either<Arc<T>, T>) -> Self {
+        match value {
+            Either::Left(shared) => CowOwner::Shared(shared),
+            Either::Right(owned) => CowOwner::Owned(Box::new(owned)),
+        }
+    }
+
+    /// Converts a `Shared` owner into an `Owned` one, cloning the target unless this was the
+    /// last reference to it. Note that this moves the target, so it can't be done while a
+    /// bundle borrows from it; use `BorrowedWithOwner::promote` for that.
+    pub fn into_owned(self) -> Self
+    where
+        T: Clone,
+    {
+        match self {
+            CowOwner::Shared(shared) => CowOwner::Owned(Box::new(Arc::unwrap_or_clone(shared))),
+            owned @ CowOwner::Owned(_) => owned,
+        }
+    }
+}
+
+impl<T: ?Sized> CowOwner<T> {
+    /// Returns `true` if this is an `Owned` owner
+    pub fn is_owned(&self) -> bool {
+        matches!(self, CowOwner::Owned(_))
+    }
+}
+
+impl<T: ?Sized> Deref for CowOwner<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            CowOwner::Shared(shared) => shared,
+            CowOwner::Owned(owned) => owned,
+        }
+    }
+}
+
+// both variants keep their target on the heap
+unsafe impl<T: ?Sized> StableDeref for CowOwner<T> {}
+
+// like `Arc`, `CowOwner` never gives out `&mut T`, even in the `Owned` case
+impl<T: ?Sized> sealed::Sealed for CowOwner<T> {}
+impl<T: ?Sized> OwnerCanBeShared for CowOwner<T> {}
+
+impl<B, T> BorrowedWithOwner<B, CowOwner<T>>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    T: Clone + 'static,
+{
+    /// Promotes a `Shared` owner to an `Owned` one. Since the owned copy lives at a different
+    /// address, the borrowed value is dropped and rebuilt by calling `f` with the new target.
+    /// Bundles whose owner is already `Owned` are returned unchanged, without calling `f`.
+    pub fn promote<F>(self, f: F) -> Self
+    where
+        F: for<'a> FnOnce(&'a T, Lifetime<'a>) -> <B as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        if self.owner.is_owned() {
+            return self;
+        }
+
+        RefWithOwner::new(self.into_owner().into_owned()).map(f)
+    }
+}
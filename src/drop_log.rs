@@ -0,0 +1,65 @@
+//! Diagnostics for finding out when bundles are dropped
+
+use stable_deref_trait::{CloneStableDeref, StableDeref};
+use std::ops::{Deref, DerefMut};
+use std::sync::RwLock;
+
+static DROP_HOOK: RwLock<Option<fn(&'static str)>> = RwLock::new(None);
+
+/// Registers `hook` to be called with the type name of the wrapped owner whenever a `DropLogged`
+/// owner is dropped, replacing the previously registered hook
+pub fn set_drop_hook(hook: fn(&'static str)) {
+    *DROP_HOOK.write().unwrap_or_else(|err| err.into_inner()) = Some(hook);
+}
+
+/// Unregisters the hook registered with `set_drop_hook`
+pub fn clear_drop_hook() {
+    *DROP_HOOK.write().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+/// An owner that calls the hook registered with `set_drop_hook` when it is dropped
+///
+/// Wrapping a bundle's owner in `DropLogged` makes it possible to see when the bundle is dropped
+/// (or, if the owner is taken out of the bundle with `.into_owner()`, when the owner is).
+pub struct DropLogged<O>(O);
+
+impl<O> DropLogged<O> {
+    /// Wraps `owner`; the hook fires when the owner is dropped, not when the bundle is dropped
+    pub fn new(owner: O) -> Self {
+        DropLogged(owner)
+    }
+}
+
+impl<O: Deref> Deref for DropLogged<O> {
+    type Target = O::Target;
+
+    fn deref(&self) -> &O::Target {
+        &self.0
+    }
+}
+
+impl<O: DerefMut> DerefMut for DropLogged<O> {
+    fn deref_mut(&mut self) -> &mut O::Target {
+        &mut self.0
+    }
+}
+
+// `DropLogged<O>` derefs to wherever `O` does
+unsafe impl<O: StableDeref> StableDeref for DropLogged<O> {}
+
+impl<O: Clone> Clone for DropLogged<O> {
+    fn clone(&self) -> Self {
+        DropLogged(self.0.clone())
+    }
+}
+
+unsafe impl<O: CloneStableDeref> CloneStableDeref for DropLogged<O> {}
+
+impl<O> Drop for DropLogged<O> {
+    fn drop(&mut self) {
+        let hook = *DROP_HOOK.read().unwrap_or_else(|err| err.into_inner());
+        if let Some(hook) = hook {
+            hook(std::any::type_name::<O>());
+        }
+    }
+}
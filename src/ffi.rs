@@ -0,0 +1,39 @@
+use crate::BorrowedWithOwner;
+use stable_deref_trait::StableDeref;
+use std::ffi::{CStr, FromBytesWithNulError, OsStr};
+
+impl<O> BorrowedWithOwner<&'static [u8], O>
+where
+    O: StableDeref,
+{
+    /// Reinterprets the borrowed byte slice as a `CStr`. The slice must end with a nul byte and
+    /// not contain any other nul bytes; otherwise the error is returned along with `self`.
+    pub fn cstr_ref(
+        self,
+    ) -> Result<BorrowedWithOwner<&'static CStr, O>, (FromBytesWithNulError, Self)> {
+        if let Err(err) = CStr::from_bytes_with_nul(self.borrowed()) {
+            return Err((err, self));
+        }
+
+        Ok(self.map(|bytes, _| match CStr::from_bytes_with_nul(bytes) {
+            Ok(cstr) => cstr,
+            Err(_) => unreachable!("the nul terminator was already validated"),
+        }))
+    }
+}
+
+impl<O> BorrowedWithOwner<&'static OsStr, O>
+where
+    O: StableDeref,
+{
+    /// Returns the encoded bytes of the borrowed `OsStr` (see `OsStr::as_encoded_bytes`)
+    pub fn as_encoded_bytes_borrowed(&self) -> &[u8] {
+        self.borrowed().as_encoded_bytes()
+    }
+
+    /// Returns the raw bytes of the borrowed `OsStr`
+    #[cfg(unix)]
+    pub fn as_bytes_borrowed(&self) -> &[u8] {
+        std::os::unix::ffi::OsStrExt::as_bytes(*self.borrowed())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner, Lifetime};
+use alloc::boxed::Box;
+use core::future::Future;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use stable_deref_trait::StableDeref;
+
+/// Marker for a boxed future, which may borrow from the owner, that resolves to a `T`. The future
+/// has to be `Send`, so the bundle can be spawned on a multi-threaded executor; use
+/// `LocalBoxFuture` for futures that aren't.
+pub struct BoxFuture<T>(PhantomData<T>);
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for BoxFuture<T> {
+    type Borrowed = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+}
+
+/// Same as `BoxFuture`, but the future doesn't have to be `Send`, e.g. because it holds an `Rc`
+/// or a `RefCell` borrow across an `.await`
+pub struct LocalBoxFuture<T>(PhantomData<T>);
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for LocalBoxFuture<T> {
+    type Borrowed = Pin<Box<dyn Future<Output = T> + 'a>>;
+}
+
+impl<B, O> BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: StableDeref,
+{
+    /// Calls `f` with the borrowed value, and stores the future that it returns in a new
+    /// `BorrowedWithOwner` so it can be polled later. The future can be awaited with
+    /// `bundle.borrowed_mut().await`.
+    pub fn map_store_future<T, F>(self, f: F) -> BorrowedWithOwner<BoxFuture<T>, O>
+    where
+        T: 'static,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> Pin<Box<dyn Future<Output = T> + Send + 'a>>,
+    {
+        self.map::<BoxFuture<T>, _>(f)
+    }
+    /// Same as `map_store_future`, but for futures that aren't `Send`
+    pub fn map_store_local_future<T, F>(self, f: F) -> BorrowedWithOwner<LocalBoxFuture<T>, O>
+    where
+        T: 'static,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> Pin<Box<dyn Future<Output = T> + 'a>>,
+    {
+        self.map::<LocalBoxFuture<T>, _>(f)
+    }
+}
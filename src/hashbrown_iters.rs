@@ -0,0 +1,28 @@
+//! `BorrowWithLifetime` impls for iterators from `hashbrown`
+
+use crate::BorrowWithLifetime;
+use hashbrown::{hash_map, hash_set};
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::Iter<'static, K, V> {
+    type Borrowed = hash_map::Iter<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::IterMut<'static, K, V> {
+    type Borrowed = hash_map::IterMut<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::Keys<'static, K, V> {
+    type Borrowed = hash_map::Keys<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::Values<'static, K, V> {
+    type Borrowed = hash_map::Values<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for hash_map::ValuesMut<'static, K, V> {
+    type Borrowed = hash_map::ValuesMut<'a, K, V>;
+}
+
+impl<'a, K: 'static> BorrowWithLifetime<'a> for hash_set::Iter<'static, K> {
+    type Borrowed = hash_set::Iter<'a, K>;
+}
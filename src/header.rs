@@ -0,0 +1,102 @@
+use crate::BorrowedWithOwner;
+use stable_deref_trait::StableDeref;
+use std::fmt;
+use zerocopy::{ConvertError, FromBytes, Immutable, KnownLayout};
+
+/// The reason that `.header_ref()` couldn't reinterpret the start of a byte slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderError {
+    /// The byte slice is shorter than the header
+    TooShort,
+    /// The byte slice isn't sufficiently aligned for the header
+    Misaligned,
+}
+
+impl fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeaderError::TooShort => f.write_str("byte slice is too short for the header"),
+            HeaderError::Misaligned => f.write_str("byte slice is misaligned for the header"),
+        }
+    }
+}
+
+impl std::error::Error for HeaderError {}
+
+/// The reason that `.try_map_cast()` couldn't reinterpret a byte slice as a typed slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastError {
+    /// The byte slice's length isn't a multiple of the element size
+    Size,
+    /// The byte slice isn't sufficiently aligned for the element type
+    Misaligned,
+    /// The validation function rejected the typed slice
+    Invalid,
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CastError::Size => {
+                f.write_str("byte slice length isn't a multiple of the element size")
+            }
+            CastError::Misaligned => f.write_str("byte slice is misaligned for the element type"),
+            CastError::Invalid => f.write_str("typed slice failed validation"),
+        }
+    }
+}
+
+impl std::error::Error for CastError {}
+
+impl<O> BorrowedWithOwner<&'static [u8], O>
+where
+    O: StableDeref,
+{
+    /// Reinterprets the start of the borrowed byte slice as a reference to a header of type `H`.
+    /// If the slice is too short or misaligned, the error is returned along with `self`.
+    pub fn header_ref<H>(self) -> Result<BorrowedWithOwner<&'static H, O>, (HeaderError, Self)>
+    where
+        H: FromBytes + KnownLayout + Immutable + 'static,
+    {
+        if let Err(err) = H::ref_from_prefix(self.borrowed()) {
+            let err = match err {
+                ConvertError::Alignment(_) => HeaderError::Misaligned,
+                ConvertError::Size(_) => HeaderError::TooShort,
+                ConvertError::Validity(infallible) => match infallible {},
+            };
+            return Err((err, self));
+        }
+
+        Ok(self.map(|bytes, _| match H::ref_from_prefix(bytes) {
+            Ok((header, _)) => header,
+            Err(_) => unreachable!("the header was already validated"),
+        }))
+    }
+
+    /// Reinterprets the borrowed byte slice as a slice of `U`s, and calls `validate` with it to
+    /// check its contents. If the slice's length or alignment is wrong for `U`, or `validate`
+    /// returns `false`, the error is returned along with `self`.
+    pub fn try_map_cast<U, F>(
+        self,
+        validate: F,
+    ) -> Result<BorrowedWithOwner<&'static [U], O>, (CastError, Self)>
+    where
+        U: FromBytes + Immutable + 'static,
+        F: FnOnce(&[U]) -> bool,
+    {
+        let valid = match <[U]>::ref_from_bytes(self.borrowed()) {
+            Ok(typed) => validate(typed),
+            Err(ConvertError::Alignment(_)) => return Err((CastError::Misaligned, self)),
+            Err(ConvertError::Size(_)) => return Err((CastError::Size, self)),
+            Err(ConvertError::Validity(infallible)) => match infallible {},
+        };
+        if !valid {
+            return Err((CastError::Invalid, self));
+        }
+
+        Ok(self.map(|bytes, _| match <[U]>::ref_from_bytes(bytes) {
+            Ok(typed) => typed,
+            Err(_) => unreachable!("the cast was already checked"),
+        }))
+    }
+}
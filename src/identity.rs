@@ -0,0 +1,82 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner, OwnerCanBeShared};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// A wrapper around a `BorrowedWithOwner` that implements `Hash` and `Eq` based on the identity
+/// of its owner (see `BorrowedWithOwner::owner_ptr`), rather than the borrowed value
+pub struct ByOwnerIdentity<B, O>(pub BorrowedWithOwner<B, O>)
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: OwnerCanBeShared;
+
+/// A wrapper around a `BorrowedWithOwner` that implements `Ord` based on the address of its owner
+/// (see `BorrowedWithOwner::owner_ptr`), so that bundles can be kept in a sorted `Vec` and
+/// binary-searched by owner identity. The order is arbitrary and unrelated to the borrowed values.
+pub struct ByOwnerOrder<B, O>(pub BorrowedWithOwner<B, O>)
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: OwnerCanBeShared;
+
+fn owner_addr<B, O>(bundle: &BorrowedWithOwner<B, O>) -> *const ()
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: OwnerCanBeShared,
+{
+    bundle.owner_ptr() as *const ()
+}
+
+// both wrappers are equal exactly when their owners are the same
+macro_rules! eq_by_owner {
+    ($($wrapper:ident)+) => {
+        $(
+            impl<B, O> PartialEq for $wrapper<B, O>
+            where
+                B: for<'a> BorrowWithLifetime<'a>,
+                O: OwnerCanBeShared,
+            {
+                fn eq(&self, other: &Self) -> bool {
+                    owner_addr(&self.0) == owner_addr(&other.0)
+                }
+            }
+
+            impl<B, O> Eq for $wrapper<B, O>
+            where
+                B: for<'a> BorrowWithLifetime<'a>,
+                O: OwnerCanBeShared,
+            {
+            }
+        )+
+    };
+}
+
+eq_by_owner!(ByOwnerIdentity ByOwnerOrder);
+
+impl<B, O> Hash for ByOwnerIdentity<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: OwnerCanBeShared,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        owner_addr(&self.0).hash(state)
+    }
+}
+
+impl<B, O> PartialOrd for ByOwnerOrder<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: OwnerCanBeShared,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<B, O> Ord for ByOwnerOrder<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: OwnerCanBeShared,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        owner_addr(&self.0).cmp(&owner_addr(&other.0))
+    }
+}
@@ -0,0 +1,36 @@
+//! `BorrowWithLifetime` impls for iterators from `indexmap`
+
+use crate::{BorrowWithLifetime, BorrowedWithOwner};
+use indexmap::map::{Iter, Keys, Values};
+use indexmap::IndexMap;
+use stable_deref_trait::StableDeref;
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for Iter<'static, K, V> {
+    type Borrowed = Iter<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for Keys<'static, K, V> {
+    type Borrowed = Keys<'a, K, V>;
+}
+
+impl<'a, K: 'static, V: 'static> BorrowWithLifetime<'a> for Values<'static, K, V> {
+    type Borrowed = Values<'a, K, V>;
+}
+
+impl<K, V, S, O> BorrowedWithOwner<&'static IndexMap<K, V, S>, O>
+where
+    K: 'static,
+    V: 'static,
+    S: 'static,
+    O: StableDeref,
+{
+    /// Returns an iterator over the entries of the borrowed `IndexMap`, along with its owner
+    pub fn iter_with_owner(self) -> BorrowedWithOwner<Iter<'static, K, V>, O> {
+        self.map(|map, _| map.iter())
+    }
+
+    /// Returns an iterator over the values of the borrowed `IndexMap`, along with its owner
+    pub fn values_with_owner(self) -> BorrowedWithOwner<Values<'static, K, V>, O> {
+        self.map(|map, _| map.values())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner};
+use stable_deref_trait::StableDeref;
+use std::io::{self, BufRead, Read, Write};
+
+/// Marker for a `std::io::Lines` iterator over a borrowed byte slice
+pub struct SliceLines;
+
+impl<'a> BorrowWithLifetime<'a> for SliceLines {
+    type Borrowed = io::Lines<&'a [u8]>;
+}
+
+impl<O> BorrowedWithOwner<&'static [u8], O>
+where
+    O: StableDeref,
+{
+    /// Returns an iterator over the lines of the borrowed byte slice, along with its owner
+    pub fn lines_with_owner(self) -> BorrowedWithOwner<SliceLines, O> {
+        self.map(|bytes, _| bytes.lines())
+    }
+}
+
+/// A bundled reader, like a `Cursor<&[u8]>` over a buffer in the owner, is a reader itself
+impl<B, O> Read for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Read,
+    O: StableDeref,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.borrowed_mut().read(buf)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.borrowed_mut().read_exact(buf)
+    }
+}
+
+impl<B, O> BufRead for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: BufRead,
+    O: StableDeref,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.borrowed_mut().fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.borrowed_mut().consume(amt)
+    }
+}
+
+/// A bundled writer, like an `&mut Vec<u8>` borrowed from the owner, is a writer itself
+impl<B, O> Write for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Write,
+    O: StableDeref,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.borrowed_mut().write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.borrowed_mut().write_all(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.borrowed_mut().flush()
+    }
+}
@@ -73,6 +73,38 @@ std::thread::spawn(move || {
 }).join().unwrap();
 ```
 
+## Composing bundles
+
+Since `Vec<T>` is `StableDeref`, a `Vec` of bundles can itself be used as an owner. The child bundles live in the `Vec`'s heap buffer, which doesn't move when the parent bundle is moved, so the parent's borrowed value can borrow from the children's borrowed values by calling `.borrowed()` on them inside of `.map()`:
+
+```
+use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+
+struct ChainedChars;
+
+impl<'a> BorrowWithLifetime<'a> for ChainedChars {
+    type Borrowed = std::iter::Chain<std::str::Chars<'a>, std::str::Chars<'a>>;
+}
+
+let children = vec![
+    RefWithOwner::new(String::from("ab")),
+    RefWithOwner::new(String::from("cd")),
+];
+
+let mut parent = RefWithOwner::new(children)
+    .map::<ChainedChars, _>(|children, _| children[0].borrowed().chars().chain(children[1].borrowed().chars()));
+
+assert_eq!(parent.borrowed_mut().collect::<String>(), "abcd");
+```
+
+## Deriving `BorrowWithLifetime`
+
+With the `derive` feature enabled, `#[derive(BorrowWithLifetime)]` implements the trait for a struct or enum with exactly one lifetime parameter, using the type with a `'static` lifetime as the marker.
+
+## `no_std`
+
+The crate can be used without `std` by disabling the default `std` feature. Enable the `alloc` feature to keep the impls for `Box`, `Vec`, `String`, `Rc`, and `Arc`. The integrations with other crates, like `serde` and `rayon`, require `std`.
+
 ## Soundness assumptions
 
 This library is believed to be sound based on the following assumption:
@@ -81,11 +113,113 @@ This library is believed to be sound based on the following assumption:
 
 If you know a counter-example to this assumption, whether in stable Rust or in nightly using unstable features (such as soundness holes in specialization), or if you notice any other soundness issues with this libary, please let me know by [opening an issue](https://github.com/mikeyhew/borrowed_with_owner/issues/new).
 */
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "async-stream")]
+mod async_stream_impls;
+#[cfg(feature = "alloc")]
+mod boxed_iter;
+#[cfg(feature = "alloc")]
+mod boxed_unsized;
+mod brand;
+#[cfg(feature = "bumpalo")]
+mod bumpalo_impls;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
+#[cfg(feature = "alloc")]
+mod collections_iters;
+#[cfg(feature = "alloc")]
+mod cow_owner;
+#[cfg(feature = "drop_log")]
+pub mod drop_log;
+#[cfg(feature = "std")]
+mod ffi;
+#[cfg(feature = "alloc")]
+mod future;
+#[cfg(feature = "hashbrown")]
+mod hashbrown_iters;
+#[cfg(feature = "zerocopy")]
+mod header;
+mod identity;
+#[cfg(feature = "indexmap")]
+mod indexmap_iters;
+#[cfg(feature = "std")]
+mod io;
+mod owner_pair;
+#[cfg(feature = "owning_ref")]
+mod owning_ref_impls;
+#[cfg(feature = "rayon")]
+mod rayon_impls;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "simd-json")]
+mod simd_json_impls;
+mod slice;
+mod slice_iters;
+#[cfg(feature = "smallvec")]
+mod smallvec_iters;
+mod str_iters;
+mod viewer;
+
+#[cfg(feature = "async-stream")]
+pub use async_stream_impls::{BoxStream, LocalBoxStream};
+#[cfg(feature = "derive")]
+pub use borrowed_with_owner_derive::BorrowWithLifetime;
+#[cfg(feature = "alloc")]
+pub use boxed_iter::BoxedIter;
+#[cfg(feature = "alloc")]
+pub use boxed_unsized::{BoxedUnsized, UnsizedWithLifetime};
+pub use brand::Branded;
+#[cfg(feature = "alloc")]
+pub use cow_owner::CowOwner;
+pub use either::Either;
+#[cfg(feature = "alloc")]
+pub use future::{BoxFuture, LocalBoxFuture};
+#[cfg(feature = "zerocopy")]
+pub use header::{CastError, HeaderError};
+pub use identity::{ByOwnerIdentity, ByOwnerOrder};
+#[cfg(feature = "std")]
+pub use io::SliceLines;
+pub use owner_pair::{zip, OwnerPair};
+#[cfg(feature = "serde")]
+pub use serde_impls::BytesBundle;
+#[cfg(feature = "simd-json")]
+pub use simd_json_impls::JsonBundle;
+pub use str_iters::{
+    BytesWithOwner, CharIndicesWithOwner, CharsWithOwner, LinesWithOwner,
+    SplitAsciiWhitespaceWithOwner, SplitWhitespaceWithOwner,
+};
+pub use viewer::Viewer;
 
+#[cfg(feature = "alloc")]
+use alloc::{rc::Rc, string::String, sync::Arc, vec::Vec};
+use core::any::Any;
+use core::cell::{Ref, RefCell, RefMut};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
 use stable_deref_trait::{CloneStableDeref, StableDeref};
-use std::ops::{Deref, DerefMut};
+#[cfg(feature = "std")]
+use std::borrow::Borrow;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::BuildHasher;
+#[cfg(feature = "std")]
+use std::sync::{LockResult, Mutex, MutexGuard, RwLockReadGuard, RwLockWriteGuard};
 
 /// An immutable (`&T`) reference along with its owner, `O`
+///
+/// To bundle a reference to a whole value, rather than the target of some pointer type, use
+/// `Box<T>` as the owner: it derefs to the `T` itself, and keeps it at a stable address.
 pub type RefWithOwner<O> = BorrowedWithOwner<&'static <O as Deref>::Target, O>;
 
 /// A mutable (`&mut T`) reference along with its owner, `O`
@@ -98,6 +232,21 @@ pub type RefMutWithOwner<O> = BorrowedWithOwner<&'static mut <O as Deref>::Targe
 /// `<B as BorrowWithLifetime<'a>>::Borrowed` is the type of the borrowed object,
 /// where `'a` is the lifetime of the borrow of the `BorrowedWithOwner` struct
 /// when calling the `.borrowed()` or `borrowed_mut()` methods.
+///
+/// A `BorrowedWithOwner` is `Send` or `Sync` only if both the borrowed value (with the `'static`
+/// lifetime) and the owner are. For example, a bundled `&Cell<T>` can be used to mutate the owner's
+/// data from a single thread, but it can't be sent to another thread:
+///
+/// ```compile_fail
+/// use borrowed_with_owner::RefWithOwner;
+/// use std::cell::Cell;
+///
+/// let cell = RefWithOwner::new(Box::new(Cell::new(0)));
+/// std::thread::spawn(move || cell.borrowed().set(1));
+/// ```
+///
+/// Bundled references to `Sync` types, like `&[AtomicU8]`, can be sent to (and shared between)
+/// other threads as long as the owner is `Send` (and `Sync`).
 pub struct BorrowedWithOwner<B, O>
 where
     B: for<'a> BorrowWithLifetime<'a>,
@@ -127,6 +276,27 @@ where
 
         Self { owner, borrowed }
     }
+
+    /// Same as `new`, but requires the result to be `Send`, so that a bundle that is meant to be
+    /// moved to another thread fails to compile here rather than where it is sent
+    pub fn new_send(owner: O) -> Self
+    where
+        Self: Send,
+    {
+        Self::new(owner)
+    }
+
+    /// Same as `new`, but fails to compile if `Self` is larger than `N` bytes. This is useful for
+    /// keeping an eye on stack usage, e.g. on embedded targets.
+    pub fn new_max_size<const N: usize>(owner: O) -> Self {
+        const {
+            assert!(
+                core::mem::size_of::<Self>() <= N,
+                "bundle is larger than the size budget"
+            )
+        };
+        Self::new(owner)
+    }
 }
 
 impl<O> RefMutWithOwner<O>
@@ -141,6 +311,138 @@ where
 
         Self { owner, borrowed }
     }
+
+    /// Same as `new`, but requires the result to be `Send`, so that a bundle that is meant to be
+    /// moved to another thread fails to compile here rather than where it is sent
+    pub fn new_send(owner: O) -> Self
+    where
+        Self: Send,
+    {
+        Self::new(owner)
+    }
+}
+
+impl<O, T, F> BorrowedWithOwner<RefMut<'static, F>, O>
+where
+    O: StableDeref<Target = RefCell<T>>,
+    T: ?Sized + 'static,
+    F: ?Sized + 'static,
+{
+    /// Mutably borrows the `RefCell` that `owner` points to, and uses `project` to narrow the
+    /// `RefMut` guard down to a field of its contents
+    ///
+    /// The projected reference lives inside of the guard, so it is dropped before the guard,
+    /// which is in turn dropped before the owner.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `RefCell` is currently borrowed.
+    pub fn borrow_mut_field<P>(owner: O, project: P) -> Self
+    where
+        P: FnOnce(&mut T) -> &mut F,
+    {
+        RefWithOwner::new(owner).map(|cell, _| RefMut::map(cell.borrow_mut(), project))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, O> BorrowedWithOwner<&'static mut Vec<T>, O>
+where
+    T: 'static,
+    O: StableDeref,
+{
+    /// Calls `Vec::retain` on the borrowed `Vec`
+    pub fn retain_borrowed<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.borrowed_mut().retain(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S, O> BorrowedWithOwner<&'static mut HashMap<K, V, S>, O>
+where
+    K: Eq + Hash + 'static,
+    V: 'static,
+    S: BuildHasher + 'static,
+    O: StableDeref,
+{
+    /// Calls `HashMap::insert` on the borrowed `HashMap`
+    pub fn insert_borrowed(&mut self, key: K, value: V) -> Option<V> {
+        self.borrowed_mut().insert(key, value)
+    }
+
+    /// Calls `HashMap::remove` on the borrowed `HashMap`
+    pub fn remove_borrowed<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.borrowed_mut().remove(key)
+    }
+}
+
+impl<T, O> BorrowedWithOwner<&'static mut T, O>
+where
+    T: ?Sized + 'static,
+    O: StableDeref,
+{
+    /// Narrows the borrowed `&mut T` down to an `&mut U`, e.g. a field of `T`
+    pub fn project_mut<U, F>(self, f: F) -> BorrowedWithOwner<&'static mut U, O>
+    where
+        U: ?Sized + 'static,
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        self.map(|borrowed, _| f(borrowed))
+    }
+}
+
+impl<T, O> BorrowedWithOwner<&'static T, O>
+where
+    T: Deref + ?Sized + 'static,
+    <T as Deref>::Target: 'static,
+    O: StableDeref,
+{
+    /// Derefs the borrowed `&T` one level further, e.g. from `&Vec<T>` to `&[T]`, which is useful
+    /// for nested owners like `Arc<Vec<T>>` or `Box<Box<str>>`
+    pub fn map_deref(self) -> BorrowedWithOwner<&'static <T as Deref>::Target, O> {
+        self.map(|borrowed, _| &**borrowed)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<O> BorrowedWithOwner<&'static mut String, O>
+where
+    O: StableDeref,
+{
+    /// Converts the borrowed `&mut String` into an `&mut str`
+    pub fn as_mut_str(self) -> BorrowedWithOwner<&'static mut str, O> {
+        self.map(|s, _| s.as_mut_str())
+    }
+}
+
+impl<O> BorrowedWithOwner<&'static mut str, O>
+where
+    O: StableDeref,
+{
+    /// Calls `str::make_ascii_uppercase` on the borrowed `&mut str`
+    pub fn make_ascii_uppercase_borrowed(&mut self) {
+        self.borrowed_mut().make_ascii_uppercase()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, O> BorrowedWithOwner<&'static Mutex<T>, O>
+where
+    T: ?Sized + 'static,
+    O: StableDeref,
+{
+    /// Locks the borrowed `&Mutex`. The guard borrows `self`, so the bundle can be locked again
+    /// once it has been dropped.
+    pub fn lock_borrowed(&self) -> LockResult<MutexGuard<'_, T>> {
+        self.borrowed().lock()
+    }
 }
 
 impl<B, O> BorrowedWithOwner<B, O>
@@ -148,11 +450,14 @@ where
     B: for<'a> BorrowWithLifetime<'a>,
     O: StableDeref,
 {
-    // /// unsound for the same reason as `owner_mut` if `O` has interior mutability.
-    // /// just use `Box<RefCell<String>>` instead of `Box<String>` in the below example.
-    // pub fn owner(&self) -> &O {
-    //     &self.owner
-    // }
+    /// Returns an `&`-reference to the owner, e.g. to read its length or other metadata. This is
+    /// only available for owners that implement `OwnerCanBeShared`; see its docs for why.
+    pub fn owner(&self) -> &O
+    where
+        O: OwnerCanBeShared,
+    {
+        &self.owner
+    }
 
     // /// unsound because it could invalidate the borrowed value. For example:
     // /// ```
@@ -194,6 +499,64 @@ where
         self.borrowed
     }
 
+    /// Drops the owner and returns the borrowed value if `B`'s `TryDetach` impl says that it doesn't
+    /// borrow from the owner; otherwise returns `self`. Unlike `into_borrowed`, this doesn't require
+    /// the borrowed value to be `'static` at the call site.
+    pub fn try_detach(self) -> Result<<B as BorrowWithLifetime<'static>>::Borrowed, Self>
+    where
+        B: for<'a> TryDetach<'a>,
+    {
+        let Self { owner, borrowed } = self;
+
+        match B::try_detach(borrowed) {
+            Ok(detached) => Ok(detached),
+            Err(borrowed) => Err(Self { owner, borrowed }),
+        }
+    }
+
+    /// Replaces the owner with `new_owner` and returns the old one, as long as the borrowed value
+    /// doesn't actually borrow from the owner anymore
+    pub fn swap_owner(&mut self, new_owner: O) -> O
+    where
+        for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: 'static,
+    {
+        core::mem::replace(&mut self.owner, new_owner)
+    }
+
+    /// Returns the borrowed value as an `&dyn Any`, so it can be downcast at runtime, as long as
+    /// it doesn't actually borrow from the owner anymore
+    pub fn borrowed_any(&self) -> &dyn Any
+    where
+        for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: 'static,
+    {
+        &self.borrowed
+    }
+
+    /// Drives the borrowed iterator to completion with `Iterator::fold`, and returns the result
+    /// along with the owner
+    pub fn fold_consume<T, R, F>(self, init: R, f: F) -> (R, O)
+    where
+        for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Iterator<Item = T>,
+        F: FnMut(R, T) -> R,
+    {
+        let Self { owner, borrowed } = self;
+        let result = borrowed.fold(init, f);
+        (result, owner)
+    }
+
+    /// Returns a pointer to the owner's deref target. Bundles whose owners are clones of the
+    /// same `Rc` or `Arc` return the same pointer. `Rc` and `Arc` always allocate, so distinct
+    /// owners have distinct pointers, but `CowOwner::Owned` boxes of zero-sized types don't.
+    ///
+    /// Like `owner`, this is only available for owners that implement `OwnerCanBeShared`, since
+    /// it derefs the owner while the borrowed value may be borrowing from it.
+    pub fn owner_ptr(&self) -> *const <O as Deref>::Target
+    where
+        O: OwnerCanBeShared,
+    {
+        &*self.owner
+    }
+
     /// Returns an `&`-reference to the borrowed value, with lifetime tied to the borrow of `self`
     #[allow(clippy::needless_lifetimes)]
     pub fn borrowed<'a>(&'a self) -> &'a <B as BorrowWithLifetime<'a>>::Borrowed {
@@ -206,20 +569,57 @@ where
         unsafe { &mut *Self::transmute_lifetime_ptr(&mut self.borrowed) }
     }
 
+    /// Projects a pinned `&mut`-reference to the bundle to a pinned `&mut`-reference to the
+    /// borrowed value, so that a borrowed `!Unpin` future or stream can be polled in place. If
+    /// `self` is pinned, so is the borrowed value: it can only be moved through an
+    /// `&mut`-reference to the bundle, which safe code can't get from a `Pin` unless the
+    /// borrowed value is `Unpin`.
+    pub fn pinned<'a>(
+        self: Pin<&'a mut Self>,
+    ) -> Pin<&'a mut <B as BorrowWithLifetime<'a>>::Borrowed> {
+        // the borrowed value is structurally pinned: `BorrowedWithOwner` doesn't implement
+        // `Drop`, is only `Unpin` if the borrowed value is, and never moves the borrowed value out
+        // from behind an `&mut`-reference.
+        unsafe { Pin::new_unchecked(self.get_unchecked_mut().borrowed_mut()) }
+    }
+
+    /// Calls `f` with an `&`-reference to the borrowed value and returns its result. Since `R` and
+    /// `E` can't depend on the lifetime of the borrow, this can be used with `?` to propagate
+    /// errors out of a scoped access.
+    pub fn try_with_borrowed<R, E, F>(&self, f: F) -> Result<R, E>
+    where
+        F: for<'a> FnOnce(&'a <B as BorrowWithLifetime<'a>>::Borrowed) -> Result<R, E>,
+    {
+        f(self.borrowed())
+    }
+
+    /// Calls `f` with an `&mut`-reference to the borrowed value, allowing it to be updated in place.
+    /// This is useful for incremental parsing loops, where each step can fail with an error.
+    pub fn step<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        F: for<'a> FnOnce(&'a mut <B as BorrowWithLifetime<'a>>::Borrowed) -> Result<(), E>,
+    {
+        f(self.borrowed_mut())
+    }
+
     /// Calls `f` with the borrowed value, and returns a new `BorrowedWithOwner` with the value returned
-    /// by `f`. The second `&'a ()` argument to `f` is required because of compiler limitations
-    /// and can be ignored.
+    /// by `f`. The second `Lifetime<'a>` argument to `f` is required because of compiler limitations
+    /// and can be ignored. If `f` panics, the panic is reported at `f`'s own location, in the
+    /// caller's code.
     pub fn map<B2, F>(self, f: F) -> BorrowedWithOwner<B2, O>
     where
         B2: for<'a> BorrowWithLifetime<'a>,
         F: for<'a> FnOnce(
             <B as BorrowWithLifetime<'a>>::Borrowed,
-            &'a (), // to get around "lifetime `'a` is unconstrained by the fn input types"
+            Lifetime<'a>, // to get around "lifetime `'a` is unconstrained by the fn input types"
         ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
     {
         let Self { owner, borrowed } = self;
 
-        let borrowed2 = f(unsafe { Self::transmute_lifetime(borrowed) }, &());
+        let borrowed2 = f(
+            unsafe { Self::transmute_lifetime(borrowed) },
+            Lifetime::new(),
+        );
 
         BorrowedWithOwner {
             owner,
@@ -227,32 +627,295 @@ where
         }
     }
 
+    /// Same as `map`, but `f` can fail. If it returns `Err`, the error is returned along with the
+    /// owner, so the owner isn't lost and can be used to try again.
+    pub fn try_map<B2, E, F>(self, f: F) -> Result<BorrowedWithOwner<B2, O>, (E, O)>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> Result<<B2 as BorrowWithLifetime<'a>>::Borrowed, E>,
+    {
+        let Self { owner, borrowed } = self;
+
+        // `f` has consumed the old borrowed value by the time it returns, so on the error path
+        // nothing borrows from `owner` any more and it's safe to hand it back
+        match f(
+            unsafe { Self::transmute_lifetime(borrowed) },
+            Lifetime::new(),
+        ) {
+            Ok(borrowed2) => Ok(BorrowedWithOwner {
+                owner,
+                borrowed: unsafe { BorrowedWithOwner::<B2, O>::transmute_lifetime(borrowed2) },
+            }),
+            Err(e) => Err((e, owner)),
+        }
+    }
+
+    /// Same as `map`, but `f` returns an `Option`. If it returns `None`, the owner is returned
+    /// instead, for projections that may not exist. As with `try_map`, `f` has consumed and
+    /// dropped the old borrowed value by the time the owner is handed back.
+    pub fn map_opt<B2, F>(self, f: F) -> Result<BorrowedWithOwner<B2, O>, O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> Option<<B2 as BorrowWithLifetime<'a>>::Borrowed>,
+    {
+        self.try_map(|borrowed, lifetime| f(borrowed, lifetime).ok_or(()))
+            .map_err(|((), owner)| owner)
+    }
+
+    /// Same as `map`, but named to read better in a chain of transforms where each step is derived
+    /// from the previous one. The previous borrowed value is moved into `f`, so `f` can reuse any
+    /// allocations it holds. `f` isn't given access to the owner, since the previous borrowed
+    /// value could hold an exclusive borrow of it.
+    ///
+    /// ```
+    /// use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+    ///
+    /// struct Split;
+    /// impl<'a> BorrowWithLifetime<'a> for Split {
+    ///     type Borrowed = std::str::Split<'a, char>;
+    /// }
+    ///
+    /// struct Words;
+    /// impl<'a> BorrowWithLifetime<'a> for Words {
+    ///     type Borrowed = Vec<&'a str>;
+    /// }
+    ///
+    /// let words = RefWithOwner::new(String::from("a b  c"))
+    ///     .and_then::<Split, _>(|s, _| s.split(' '))
+    ///     .and_then::<Words, _>(|split, _| split.filter(|word| !word.is_empty()).collect());
+    ///
+    /// assert_eq!(*words.borrowed(), ["a", "b", "c"]);
+    /// ```
+    pub fn and_then<B2, F>(self, f: F) -> BorrowedWithOwner<B2, O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        self.map(f)
+    }
+
+    /// Same as `and_then`, but `f` can fail, in which case the error is returned along with the
+    /// owner, like `try_map`
+    ///
+    /// ```
+    /// use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+    ///
+    /// struct Split;
+    /// impl<'a> BorrowWithLifetime<'a> for Split {
+    ///     type Borrowed = std::str::Split<'a, char>;
+    /// }
+    ///
+    /// struct Words;
+    /// impl<'a> BorrowWithLifetime<'a> for Words {
+    ///     type Borrowed = Vec<&'a str>;
+    /// }
+    ///
+    /// let result = RefWithOwner::new(String::from("a  b"))
+    ///     .and_then::<Split, _>(|s, _| s.split(' '))
+    ///     .try_and_then::<Words, _, _>(|split, _| {
+    ///         split
+    ///             .map(|word| if word.is_empty() { Err("empty word") } else { Ok(word) })
+    ///             .collect()
+    ///     });
+    ///
+    /// let (err, owner) = result.err().unwrap();
+    /// assert_eq!(err, "empty word");
+    /// assert_eq!(owner, "a  b");
+    /// ```
+    pub fn try_and_then<B2, E, F>(self, f: F) -> Result<BorrowedWithOwner<B2, O>, (E, O)>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> Result<<B2 as BorrowWithLifetime<'a>>::Borrowed, E>,
+    {
+        self.try_map(f)
+    }
+
+    /// Same as `map`, but also passes `f` an `&mut`-reference to an accumulator, `acc`, which is
+    /// returned along with the new `BorrowedWithOwner`
+    pub fn map_acc<B2, A, F>(self, mut acc: A, f: F) -> (BorrowedWithOwner<B2, O>, A)
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            &mut A,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        let mapped = self.map(|borrowed, lifetime| f(borrowed, &mut acc, lifetime));
+        (mapped, acc)
+    }
+
+    /// Same as `map`, but calls `check` with the new borrowed value before returning it. This can
+    /// be used to assert invariants about the new borrowed value.
+    pub fn map_checked<B2, F, C>(self, f: F, check: C) -> BorrowedWithOwner<B2, O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+        C: for<'a> FnOnce(&'a <B2 as BorrowWithLifetime<'a>>::Borrowed),
+    {
+        let mapped = self.map(f);
+        check(mapped.borrowed());
+        mapped
+    }
+
+    /// Drops the borrowed value, calls `mutate` with an `&mut`-reference to the owner, and then
+    /// calls `f` with an `&`-reference to the owner's target to derive a new borrowed value
+    pub fn remap<B2, M, F>(self, mutate: M, f: F) -> BorrowedWithOwner<B2, O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        <O as Deref>::Target: 'static,
+        M: FnOnce(&mut O),
+        F: for<'a> FnOnce(
+            &'a <O as Deref>::Target,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        let mut owner = self.into_owner();
+        mutate(&mut owner);
+        RefWithOwner::new(owner).map(f)
+    }
+
     /// changes the lifetime of a `*mut Borrowed<'a>` to a `*mut Borrowed<'b>`
     unsafe fn transmute_lifetime_ptr<'a, 'b>(
         borrowed: *mut <B as BorrowWithLifetime<'a>>::Borrowed,
     ) -> *mut <B as BorrowWithLifetime<'b>>::Borrowed {
         // a simple pointer cast doesn't work here (i.e. borrowed as *mut _)
         // because Rust complains that the lifetimes aren't the same
-        std::mem::transmute(borrowed)
+        core::mem::transmute(borrowed)
     }
 
     /// changes the lifetime of a `Borrowed<'a>` to a `Borrowed<'b>`
     unsafe fn transmute_lifetime<'a, 'b>(
         borrowed: <B as BorrowWithLifetime<'a>>::Borrowed,
     ) -> <B as BorrowWithLifetime<'b>>::Borrowed {
-        // `std::mem::transmute` doesn't work here, because it thinks that
+        // `core::mem::transmute` doesn't work here, because it thinks that
         // `borrowed: <B as BorrowWithLifetime<'a>>::Borrowed` and
         // `<B as BorrowWithLifetime<'b>>::Borrowed` can have different layouts.
         // I believe that they must have the same layout since they only differ by
         // a lifetime (please let me know if this assumption is wrong).
-        let transmuted = std::ptr::read(Self::transmute_lifetime_ptr(
+        let transmuted = core::ptr::read(Self::transmute_lifetime_ptr(
             &borrowed as *const _ as *mut _,
         ));
-        std::mem::forget(borrowed);
+        core::mem::forget(borrowed);
         transmuted
     }
 }
 
+impl<B, O> BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Clone,
+    O: CloneStableDeref,
+{
+    /// Clones `self` once for each function in `fs`, and maps each clone with its function,
+    /// producing `N` independent bundles that share the same owner
+    pub fn views<B2, F, const N: usize>(&self, fs: [F; N]) -> [BorrowedWithOwner<B2, O>; N]
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        fs.map(|f| self.clone().map(f))
+    }
+
+    /// Same as `map`, but doesn't consume `self`. The owner is cloned, and `f` is called with a
+    /// clone of the borrowed value. `f` can't be given an `&`-reference to the borrowed value
+    /// instead, because that would borrow from `self` rather than from the owner, and the new
+    /// bundle could outlive `self`.
+    pub fn map_ref<B2, F>(&self, f: F) -> BorrowedWithOwner<B2, O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        self.clone().map(f)
+    }
+}
+
+impl<B, O> BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: CloneStableDeref,
+{
+    /// Calls `f` with the borrowed value, and splits the pair it returns into two bundles, each
+    /// with its own clone of the owner, so that either one keeps the owner's target alive
+    ///
+    /// This is restricted to `CloneStableDeref` owners like `Rc` and `Arc`, which only give out
+    /// `&`-references to their target, so neither half can get at the target mutably through
+    /// its owner. The halves can't alias each other either, since `f` returns both of them at
+    /// once from the same borrowed value.
+    pub fn map_split<B1, B2, F>(self, f: F) -> (BorrowedWithOwner<B1, O>, BorrowedWithOwner<B2, O>)
+    where
+        B1: for<'a> BorrowWithLifetime<'a>,
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> (
+            <B1 as BorrowWithLifetime<'a>>::Borrowed,
+            <B2 as BorrowWithLifetime<'a>>::Borrowed,
+        ),
+    {
+        let owner2 = self.owner.clone();
+        let BorrowedWithOwner {
+            owner,
+            borrowed: (borrowed1, borrowed2),
+        } = self.map::<(B1, B2), _>(f);
+        (
+            BorrowedWithOwner {
+                owner,
+                borrowed: borrowed1,
+            },
+            BorrowedWithOwner {
+                owner: owner2,
+                borrowed: borrowed2,
+            },
+        )
+    }
+}
+
+impl<O> BorrowedWithOwner<&'static str, O>
+where
+    O: CloneStableDeref,
+{
+    /// Splits the borrowed string on the first occurrence of `delimiter` (see `str::split_once`),
+    /// returning bundles of the parts before and after it, which share the owner. Returns `None`
+    /// if `delimiter` isn't found.
+    pub fn split_once_bundled(self, delimiter: &str) -> Option<(Self, Self)> {
+        let start = self.borrowed().find(delimiter)?;
+        let end = start + delimiter.len();
+        let before = self.clone().map(|s, _| &s[..start]);
+        let after = self.map(|s, _| &s[end..]);
+        Some((before, after))
+    }
+}
+
+/// The borrowed value is cloned as-is, rather than being re-derived from the cloned owner.
+/// This is sound because `CloneStableDeref` guarantees that a clone of the owner derefs to the
+/// same address as the original (as with `Rc` and `Arc`), so anything borrowed from the original
+/// owner is still valid for as long as the cloned owner is alive. Owners that reallocate when
+/// cloned, like `Box` or `Vec`, don't implement `CloneStableDeref`.
+///
+/// There's no `Send` or `Sync` bound, so bundles with `!Send` owners like `Rc` can be cloned too.
 impl<B, O> Clone for BorrowedWithOwner<B, O>
 where
     B: for<'a> BorrowWithLifetime<'a>,
@@ -275,6 +938,200 @@ where
 {
 }
 
+/// Only the borrowed value is printed. The owner is left out, since it could be huge.
+impl<B, O> fmt::Debug for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: fmt::Debug,
+    O: StableDeref,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BorrowedWithOwner")
+            .field("borrowed", self.borrowed())
+            .finish()
+    }
+}
+
+/// Equality only compares the borrowed values, as they are currently projected. The owners aren't
+/// compared, so two bundles with distinct owners are equal if their borrowed values are.
+impl<B, O> PartialEq for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: PartialEq,
+    O: StableDeref,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // reborrow both sides for the same lifetime, so the borrowed values have the same type
+        fn eq<'a, B, O>(
+            this: &'a BorrowedWithOwner<B, O>,
+            other: &'a BorrowedWithOwner<B, O>,
+        ) -> bool
+        where
+            B: for<'b> BorrowWithLifetime<'b>,
+            for<'b> <B as BorrowWithLifetime<'b>>::Borrowed: PartialEq,
+            O: StableDeref,
+        {
+            this.borrowed() == other.borrowed()
+        }
+
+        eq(self, other)
+    }
+}
+
+impl<B, O> Eq for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Eq,
+    O: StableDeref,
+{
+}
+
+/// Only the borrowed value is hashed, which is consistent with `PartialEq`
+impl<B, O> Hash for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Hash,
+    O: StableDeref,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.borrowed().hash(state)
+    }
+}
+
+/// The item type of a marker's borrowed iterator, which `BorrowedWithOwner`'s `Iterator` impl
+/// uses to check that the items don't depend on the borrow's lifetime. This is implemented
+/// automatically for every marker whose borrowed value is an iterator.
+pub trait BorrowedItem {
+    type Item;
+}
+
+impl<B> BorrowedItem for B
+where
+    B: BorrowWithLifetime<'static>,
+    <B as BorrowWithLifetime<'static>>::Borrowed: Iterator,
+{
+    type Item = <<B as BorrowWithLifetime<'static>>::Borrowed as Iterator>::Item;
+}
+
+/// A bundled iterator is an iterator itself, as long as its items don't borrow from the owner.
+/// This applies to iterators like `Chars` that yield owned items (`char`), but not to iterators
+/// like `slice::Iter` that yield references; for those, iterate over `.borrowed_mut()` instead.
+///
+/// Note that `BorrowedWithOwner`'s inherent methods, like `map`, take precedence over
+/// `Iterator`'s methods with the same name.
+impl<B, O> Iterator for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a> + BorrowedItem,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Iterator<Item = <B as BorrowedItem>::Item>,
+    O: StableDeref,
+{
+    type Item = <B as BorrowedItem>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.borrowed_mut().next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.borrowed().size_hint()
+    }
+}
+
+impl<B, O> DoubleEndedIterator for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a> + BorrowedItem,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed:
+        DoubleEndedIterator<Item = <B as BorrowedItem>::Item>,
+    O: StableDeref,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.borrowed_mut().next_back()
+    }
+}
+
+impl<B, O> ExactSizeIterator for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a> + BorrowedItem,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed:
+        ExactSizeIterator<Item = <B as BorrowedItem>::Item>,
+    O: StableDeref,
+{
+    fn len(&self) -> usize {
+        self.borrowed().len()
+    }
+}
+
+impl<B, O> FusedIterator for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a> + BorrowedItem,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed:
+        FusedIterator<Item = <B as BorrowedItem>::Item>,
+    O: StableDeref,
+{
+}
+
+/// A bundled `&T` derefs to the `T`, so it can be used as the owner of another bundle
+impl<T, O> Deref for BorrowedWithOwner<&'static T, O>
+where
+    T: ?Sized,
+    O: StableDeref,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.borrowed()
+    }
+}
+
+// The borrowed `&T` can only point into the owner's target (which doesn't move when the owner
+// is moved) or into data that outlives the owner, so it doesn't move when `self` is moved.
+unsafe impl<T, O> StableDeref for BorrowedWithOwner<&'static T, O>
+where
+    T: ?Sized,
+    O: StableDeref,
+{
+}
+
+// Clones of `O` deref to the same address, so clones of `self` do too.
+unsafe impl<T, O> CloneStableDeref for BorrowedWithOwner<&'static T, O>
+where
+    T: ?Sized,
+    O: CloneStableDeref,
+{
+}
+
+impl<B, T, O> BorrowedWithOwner<B, BorrowedWithOwner<&'static T, O>>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    T: ?Sized,
+    O: StableDeref,
+{
+    /// Converts a bundle whose owner is a bundled `&T` into a bundle that holds the inner owner
+    /// directly. The inner `&T` is discarded: anything borrowed through it points into the
+    /// inner owner's target (or outlives it), so it stays valid as long as the inner owner does.
+    pub fn flatten_owner(self) -> BorrowedWithOwner<B, O> {
+        let Self { borrowed, owner } = self;
+
+        BorrowedWithOwner {
+            borrowed,
+            owner: owner.owner,
+        }
+    }
+}
+
+/// A zero-sized stand-in for the lifetime `'a`, passed as the second argument to the closures
+/// given to `.map()` and friends. It is only there to constrain `'a`, and can be ignored.
+///
+/// Unlike a `&'a ()`, it can't be dereferenced or converted into a borrowed value, so it can't
+/// accidentally be returned in place of a borrow of the owner.
+#[derive(Clone, Copy, Debug)]
+pub struct Lifetime<'a>(PhantomData<fn(&'a ()) -> &'a ()>);
+
+impl<'a> Lifetime<'a> {
+    fn new() -> Self {
+        Lifetime(PhantomData)
+    }
+}
+
 /// An impl of this trait represents a type that is parameterized by a lifetime. This is used by `BorrowedWithOwner`, so it can "construct" the type of the borrowed object with the appropriate lifetime when you call one of the methods that give you access to it (e.g. `.borrowed()`, `.borrowed_mut()`, or `.map()`).
 ///
 /// For example, if you have a type `Foo<'a>`, you would implement `for<'a> BorrowWithLifetime<'a>`
@@ -295,6 +1152,155 @@ pub trait BorrowWithLifetime<'a> {
     type Borrowed: 'a;
 }
 
+/// Declares a marker type and its `BorrowWithLifetime` impl in one line
+///
+/// The borrowed type is written with the lifetime `'a`:
+///
+/// ```
+/// use borrowed_with_owner::{borrow_with_lifetime, RefWithOwner};
+///
+/// borrow_with_lifetime!(MySplit => std::str::Split<'a, &'static str>);
+///
+/// let string: String = "Hello, my name is Michael".into();
+/// let mut string_parts = RefWithOwner::new(string).map::<MySplit, _>(|string, _| string.split(", "));
+///
+/// std::thread::spawn(move || {
+///     assert_eq!(string_parts.borrowed_mut().next(), Some("Hello"));
+///     assert_eq!(string_parts.borrowed_mut().next(), Some("my name is Michael"));
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+///
+/// The marker can also take `'static` type parameters, with an optional `where` clause:
+///
+/// ```
+/// use borrowed_with_owner::{borrow_with_lifetime, RefWithOwner};
+///
+/// borrow_with_lifetime!(pub Chunks<T> => std::slice::Chunks<'a, T>);
+///
+/// let chunks = RefWithOwner::new(vec![1, 2, 3]).map::<Chunks<i32>, _>(|v, _| v.chunks(2));
+/// assert_eq!(chunks.borrowed().clone().collect::<Vec<_>>(), [&[1, 2][..], &[3]]);
+/// ```
+#[macro_export]
+macro_rules! borrow_with_lifetime {
+    ($(#[$attr:meta])* $vis:vis $name:ident => $borrowed:ty $(where $($bounds:tt)+)?) => {
+        $(#[$attr])*
+        $vis struct $name;
+
+        impl<'a> $crate::BorrowWithLifetime<'a> for $name $(where $($bounds)+)? {
+            type Borrowed = $borrowed;
+        }
+    };
+    ($(#[$attr:meta])* $vis:vis $name:ident<$($param:ident),+ $(,)?> => $borrowed:ty $(where $($bounds:tt)+)?) => {
+        $(#[$attr])*
+        $vis struct $name<$($param),+>(::core::marker::PhantomData<fn() -> ($($param,)+)>);
+
+        impl<'a, $($param: 'static),+> $crate::BorrowWithLifetime<'a> for $name<$($param),+>
+        $(where $($bounds)+)?
+        {
+            type Borrowed = $borrowed;
+        }
+    };
+}
+
+/// Maps a `RefWithOwner` to a bundle borrowing a (possibly nested) field of its borrowed value
+///
+/// `project!(bundle.a.b)` expands to `bundle.map(|borrowed, _| &borrowed.a.b)`, and
+/// `project!(mut bundle.a.b)` does the same with `&mut` for a `RefMutWithOwner`:
+///
+/// ```
+/// use borrowed_with_owner::{project, RefMutWithOwner, RefWithOwner};
+///
+/// struct Config {
+///     server: Server,
+/// }
+///
+/// struct Server {
+///     name: String,
+///     port: u16,
+/// }
+///
+/// let config = RefWithOwner::new(Box::new(Config {
+///     server: Server { name: "example".into(), port: 80 },
+/// }));
+/// let name = project!(config.server.name);
+/// assert_eq!(*name, "example");
+///
+/// let mut config = RefMutWithOwner::new(name.into_owner());
+/// let mut port = project!(mut config.server.port);
+/// **port.borrowed_mut() = 8080;
+/// assert_eq!(port.into_owner().server.port, 8080);
+/// ```
+#[macro_export]
+macro_rules! project {
+    (mut $bundle:ident $(. $field:tt)+) => {
+        $bundle.map::<&'static mut _, _>(|borrowed, _| &mut borrowed $(. $field)+)
+    };
+    ($bundle:ident $(. $field:tt)+) => {
+        $bundle.map::<&'static _, _>(|borrowed, _| &borrowed $(. $field)+)
+    };
+}
+
+/// Implemented by `BorrowWithLifetime` types to tell `BorrowedWithOwner::try_detach` whether their
+/// borrowed value can outlive the owner
+///
+/// On stable Rust, there is no way for generic code to ask whether `<B as BorrowWithLifetime<'a>>::Borrowed`
+/// depends on `'a`, so types opt in with this trait instead. `BorrowedWithOwner::try_detach` requires
+/// `for<'a> TryDetach<'a>`, so the compiler only accepts an impl that returns `Ok` if the borrowed value
+/// really doesn't depend on `'a`:
+///
+/// ```
+/// # use borrowed_with_owner::{BorrowWithLifetime, TryDetach};
+/// struct Len;
+///
+/// impl<'a> BorrowWithLifetime<'a> for Len {
+///     type Borrowed = usize;
+/// }
+///
+/// impl<'a> TryDetach<'a> for Len {
+///     fn try_detach(borrowed: usize) -> Result<usize, usize> {
+///         Ok(borrowed)
+///     }
+/// }
+/// ```
+pub trait TryDetach<'a>: for<'b> BorrowWithLifetime<'b> {
+    fn try_detach(
+        borrowed: <Self as BorrowWithLifetime<'a>>::Borrowed,
+    ) -> Result<
+        <Self as BorrowWithLifetime<'static>>::Borrowed,
+        <Self as BorrowWithLifetime<'a>>::Borrowed,
+    >;
+}
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// Owners that can be handed out by `&`-reference while the borrowed value is alive, using
+/// `BorrowedWithOwner::owner`. This trait is sealed, and is implemented for `Rc<T>`, `Arc<T>`, and
+/// `CowOwner<T>`.
+///
+/// Handing out `&O` is only sound if the borrowed value can't hold an exclusive borrow of the
+/// owner's target, which is the case for owners that don't implement `DerefMut`, since those can't
+/// be used with `RefMutWithOwner`. `Box`, `Vec`, and `String` don't qualify: for example, a
+/// `RefMutWithOwner<Box<RefCell<String>>>` can be mapped with `RefCell::get_mut` to an
+/// `&mut String`, and `owner().borrow()` would then alias it, without `RefCell` ever noticing.
+pub trait OwnerCanBeShared: StableDeref + sealed::Sealed {}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> sealed::Sealed for Rc<T> {}
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> OwnerCanBeShared for Rc<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> sealed::Sealed for Arc<T> {}
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> OwnerCanBeShared for Arc<T> {}
+
+/// `T` may have interior mutability (e.g. `Cell`, `OnceLock`, or atomics): the owner is never
+/// handed out mutably while the bundle exists, so mutating through the borrowed `&T` can't
+/// invalidate it.
 impl<'a, T: ?Sized> BorrowWithLifetime<'a> for &'static T {
     type Borrowed = &'a T;
 }
@@ -306,3 +1312,125 @@ impl<'a, T: ?Sized> BorrowWithLifetime<'a> for &'static mut T {
 impl<'a> BorrowWithLifetime<'a> for () {
     type Borrowed = ();
 }
+
+impl<'a, T: ?Sized> TryDetach<'a> for &'static T {
+    fn try_detach(borrowed: &'a T) -> Result<&'static T, &'a T> {
+        Err(borrowed)
+    }
+}
+
+impl<'a, T: ?Sized> TryDetach<'a> for &'static mut T {
+    fn try_detach(borrowed: &'a mut T) -> Result<&'static mut T, &'a mut T> {
+        Err(borrowed)
+    }
+}
+
+impl<'a> TryDetach<'a> for () {
+    fn try_detach(borrowed: ()) -> Result<(), ()> {
+        Ok(borrowed)
+    }
+}
+
+impl<'a, B> BorrowWithLifetime<'a> for Option<B>
+where
+    B: BorrowWithLifetime<'a>,
+{
+    type Borrowed = Option<<B as BorrowWithLifetime<'a>>::Borrowed>;
+}
+
+impl<'a, B, E> BorrowWithLifetime<'a> for Result<B, E>
+where
+    B: BorrowWithLifetime<'a>,
+    E: 'static,
+{
+    type Borrowed = Result<<B as BorrowWithLifetime<'a>>::Borrowed, E>;
+}
+
+macro_rules! tuple_impls {
+    ($(($($b:ident),+))+) => {
+        $(
+            impl<'a, $($b),+> BorrowWithLifetime<'a> for ($($b,)+)
+            where
+                $($b: BorrowWithLifetime<'a>,)+
+            {
+                type Borrowed = ($(<$b as BorrowWithLifetime<'a>>::Borrowed,)+);
+            }
+        )+
+    };
+}
+
+tuple_impls! {
+    (B0)
+    (B0, B1)
+    (B0, B1, B2)
+    (B0, B1, B2, B3)
+    (B0, B1, B2, B3, B4)
+    (B0, B1, B2, B3, B4, B5)
+    (B0, B1, B2, B3, B4, B5, B6)
+    (B0, B1, B2, B3, B4, B5, B6, B7)
+    (B0, B1, B2, B3, B4, B5, B6, B7, B8)
+    (B0, B1, B2, B3, B4, B5, B6, B7, B8, B9)
+    (B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10)
+    (B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11)
+}
+
+impl<'a, T: ?Sized + 'static> BorrowWithLifetime<'a> for Ref<'static, T> {
+    type Borrowed = Ref<'a, T>;
+}
+
+impl<'a, T: ?Sized + 'static> BorrowWithLifetime<'a> for RefMut<'static, T> {
+    type Borrowed = RefMut<'a, T>;
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + 'static> BorrowWithLifetime<'a> for MutexGuard<'static, T> {
+    type Borrowed = MutexGuard<'a, T>;
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + 'static> BorrowWithLifetime<'a> for RwLockReadGuard<'static, T> {
+    type Borrowed = RwLockReadGuard<'a, T>;
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized + 'static> BorrowWithLifetime<'a> for RwLockWriteGuard<'static, T> {
+    type Borrowed = RwLockWriteGuard<'a, T>;
+}
+
+/// A guard (`G`) along with a value (`R`) that borrows from the data it guards
+///
+/// `<Guarded<G, R> as BorrowWithLifetime<'a>>::Borrowed` is a `BorrowedWithOwner` whose owner
+/// is the guard and whose borrowed value is the derived value, so the derived value is always
+/// dropped before the guard, and (when stored in another `BorrowedWithOwner`) the guard is
+/// always dropped before the outer owner. This can be used to bundle a `MutexGuard`,
+/// `RwLockWriteGuard`, `RefMut`, etc. with a view into the locked data.
+pub struct Guarded<G, R>(PhantomData<(G, R)>);
+
+impl<'a, G, R> BorrowWithLifetime<'a> for Guarded<G, R>
+where
+    G: BorrowWithLifetime<'a>,
+    <G as BorrowWithLifetime<'a>>::Borrowed: StableDeref,
+    R: for<'b> BorrowWithLifetime<'b> + 'a,
+{
+    type Borrowed = BorrowedWithOwner<R, <G as BorrowWithLifetime<'a>>::Borrowed>;
+}
+
+/// A marker whose borrowed value is a zero-sized `PhantomData<&'a T>`. This can be used as a
+/// witness that something borrows a `T` from the owner, without storing an actual reference.
+pub struct PhantomBorrow<T: ?Sized>(PhantomData<T>);
+
+impl<'a, T: ?Sized + 'static> BorrowWithLifetime<'a> for PhantomBorrow<T> {
+    type Borrowed = PhantomData<&'a T>;
+}
+
+/// A marker whose borrowed value is either borrowed (`B`) or fully owned (`T`), depending on
+/// which variant of `Either` is used
+pub struct EitherBorrow<B, T>(PhantomData<(B, T)>);
+
+impl<'a, B, T> BorrowWithLifetime<'a> for EitherBorrow<B, T>
+where
+    B: BorrowWithLifetime<'a>,
+    T: 'static,
+{
+    type Borrowed = Either<<B as BorrowWithLifetime<'a>>::Borrowed, T>;
+}
@@ -0,0 +1,103 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner, Lifetime, RefWithOwner};
+use core::ops::Deref;
+use stable_deref_trait::{CloneStableDeref, StableDeref};
+
+/// An owner made up of two owners, which derefs to the first owner's target. This is the owner of
+/// a bundle whose borrowed value borrows from both, as created by `map_with_extra` or `zip`.
+#[derive(Clone, Debug)]
+pub struct OwnerPair<O, O2>(pub O, pub O2);
+
+impl<O, O2> Deref for OwnerPair<O, O2>
+where
+    O: StableDeref,
+{
+    type Target = O::Target;
+
+    fn deref(&self) -> &O::Target {
+        &self.0
+    }
+}
+
+// moving the pair moves both owners, neither of which moves its target
+unsafe impl<O, O2> StableDeref for OwnerPair<O, O2>
+where
+    O: StableDeref,
+    O2: StableDeref,
+{
+}
+
+unsafe impl<O, O2> CloneStableDeref for OwnerPair<O, O2>
+where
+    O: CloneStableDeref,
+    O2: CloneStableDeref,
+{
+}
+
+impl<B, O> BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: StableDeref,
+{
+    /// Same as `map`, but also takes ownership of `extra`'s owner, and passes `f` an `&`-reference
+    /// to its target. The new borrowed value can borrow from both owners, which are bundled
+    /// together in an `OwnerPair`.
+    pub fn map_with_extra<B2, O2, F>(
+        self,
+        extra: RefWithOwner<O2>,
+        f: F,
+    ) -> BorrowedWithOwner<B2, OwnerPair<O, O2>>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        O2: StableDeref,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            &'a <O2 as Deref>::Target,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        let Self { owner, borrowed } = self;
+        let extra = extra.into_owner();
+        // the target stays put when `extra` is moved into the pair below
+        let extra_target = &*extra as *const <O2 as Deref>::Target;
+        let owner = OwnerPair(owner, extra);
+
+        let borrowed2 = f(
+            unsafe { Self::transmute_lifetime(borrowed) },
+            unsafe { &*extra_target },
+            Lifetime::new(),
+        );
+
+        BorrowedWithOwner {
+            owner,
+            borrowed: unsafe {
+                BorrowedWithOwner::<B2, OwnerPair<O, O2>>::transmute_lifetime(borrowed2)
+            },
+        }
+    }
+}
+
+/// Combines `a` and `b` into a single bundle, whose owner is an `OwnerPair` of both owners and
+/// whose borrowed value is a tuple of both borrowed values
+pub fn zip<B, O, B2, O2>(
+    a: BorrowedWithOwner<B, O>,
+    b: BorrowedWithOwner<B2, O2>,
+) -> BorrowedWithOwner<(B, B2), OwnerPair<O, O2>>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: StableDeref,
+    B2: for<'a> BorrowWithLifetime<'a>,
+    O2: StableDeref,
+{
+    let BorrowedWithOwner { owner, borrowed } = a;
+    let BorrowedWithOwner {
+        owner: owner2,
+        borrowed: borrowed2,
+    } = b;
+
+    // each borrowed value still borrows from its own owner's target, which doesn't move when the
+    // owners are moved into the pair
+    BorrowedWithOwner {
+        owner: OwnerPair(owner, owner2),
+        borrowed: (borrowed, borrowed2),
+    }
+}
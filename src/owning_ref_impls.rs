@@ -0,0 +1,43 @@
+//! Conversions between bundled references and `owning_ref::OwningRef`, for migrating code from
+//! `owning_ref` one piece at a time.
+//!
+//! Note that `owning_ref` has known soundness issues, which these conversions don't fix: for
+//! example, `OwningRef<Box<T>, U>` can be invalidated under Rust's aliasing rules when the `Box`
+//! is moved, and `OwningRef::map_with_owner` can hand out references that outlive the owner.
+//! Converting a value that was created soundly gives a sound `BorrowedWithOwner`, but converting
+//! doesn't make an unsound `OwningRef` sound, which is why `from_owning_ref` is `unsafe`.
+
+use crate::BorrowedWithOwner;
+use owning_ref::OwningRef;
+use stable_deref_trait::StableDeref;
+
+impl<T, O> BorrowedWithOwner<&'static T, O>
+where
+    T: ?Sized + 'static,
+    O: StableDeref,
+{
+    /// Converts an `OwningRef` into a bundle with the same owner and reference
+    ///
+    /// # Safety
+    ///
+    /// The reference in `owning_ref` has to be valid for as long as its owner is alive, and must
+    /// not have been invalidated by moving the owner. `owning_ref` can't guarantee either of these
+    /// for values built with its unsound APIs, like `OwningRef::map_with_owner`, or for
+    /// `Box` owners that have been moved, so only convert values you know were created soundly.
+    pub unsafe fn from_owning_ref(owning_ref: OwningRef<O, T>) -> Self {
+        let borrowed = &*owning_ref as *const T;
+        // the reference stays valid when the owner is moved out, since `O` is `StableDeref`
+        let owner = owning_ref.into_owner();
+
+        Self {
+            owner,
+            borrowed: unsafe { &*borrowed },
+        }
+    }
+
+    /// Converts the bundle into an `OwningRef` with the same owner and reference
+    pub fn into_owning_ref(self) -> OwningRef<O, T> {
+        let Self { owner, borrowed } = self;
+        OwningRef::new(owner).map(|_| borrowed)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner};
+use rayon::prelude::*;
+use rayon::slice::Iter;
+use rayon::ThreadPool;
+use stable_deref_trait::StableDeref;
+
+impl<B, O> BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: StableDeref,
+{
+    /// Moves the bundle into a task on `pool` and calls `f` there with an `&mut`-reference to the
+    /// borrowed value, blocking until it finishes and returning its result. The bundle is dropped
+    /// on the pool once `f` returns.
+    pub fn spawn_on<R, F>(self, pool: &ThreadPool, f: F) -> R
+    where
+        Self: Send,
+        R: Send,
+        F: for<'a> FnOnce(&'a mut <B as BorrowWithLifetime<'a>>::Borrowed) -> R + Send,
+    {
+        pool.install(move || {
+            let mut this = self;
+            f(this.borrowed_mut())
+        })
+    }
+}
+
+impl<T, O> BorrowedWithOwner<&'static [T], O>
+where
+    T: Sync + 'static,
+    O: StableDeref,
+{
+    /// Calls `f` with a rayon parallel iterator over the borrowed slice. The iterator borrows
+    /// `self`, so it's scoped to the closure rather than returned.
+    pub fn with_par_iter<R, F>(&self, f: F) -> R
+    where
+        F: for<'a> FnOnce(Iter<'a, T>) -> R,
+    {
+        f(self.borrowed().par_iter())
+    }
+}
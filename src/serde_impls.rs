@@ -0,0 +1,81 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner, RefWithOwner};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::{Serialize, Serializer};
+use stable_deref_trait::StableDeref;
+use std::fmt;
+
+/// An owned byte buffer bundled with an `&[u8]` view of it
+pub type BytesBundle = RefWithOwner<Vec<u8>>;
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a byte array or a base64 string")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(bytes)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Vec<u8>, E> {
+        BASE64.decode(s).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        // the length comes from the input, so don't trust it for more than a small preallocation
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+impl BytesBundle {
+    /// Deserializes a byte array into an owned `Vec<u8>`, and bundles it with an `&[u8]` view.
+    /// Formats that have a native byte array type are supported, as are human-readable formats
+    /// (like JSON) that represent byte arrays as base64 strings or as sequences of integers.
+    pub fn deserialize_bytes<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = if deserializer.is_human_readable() {
+            // which representation is used can only be told from the input itself
+            deserializer.deserialize_any(BytesVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        };
+        bytes.map(RefWithOwner::new)
+    }
+
+    /// Serializes the bytes as a base64 string in human-readable formats, and as a byte array
+    /// otherwise, which is the counterpart of `deserialize_bytes`
+    pub fn serialize_bytes<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&BASE64.encode(self.borrowed()))
+        } else {
+            serializer.serialize_bytes(self.borrowed())
+        }
+    }
+}
+
+/// Serializes the borrowed value only. The owner is just storage, and is never serialized.
+impl<B, O> Serialize for BorrowedWithOwner<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Serialize,
+    O: StableDeref,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.borrowed().serialize(serializer)
+    }
+}
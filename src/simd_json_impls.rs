@@ -0,0 +1,30 @@
+//! Bundling a `simd_json::BorrowedValue` with the buffer it was parsed from. `simd-json` parses
+//! in place, so the value's strings borrow from a mutable byte buffer, which is why it can't
+//! usually be returned from a function or sent to another thread along with the buffer.
+//!
+//! ```
+//! use borrowed_with_owner::JsonBundle;
+//! use simd_json::prelude::*;
+//!
+//! let json = JsonBundle::parse(br#"{"name": "Michael"}"#.to_vec()).unwrap();
+//! assert_eq!(json.borrowed().get_str("name"), Some("Michael"));
+//! ```
+
+use crate::{BorrowWithLifetime, BorrowedWithOwner, RefMutWithOwner};
+use simd_json::BorrowedValue;
+
+impl<'a> BorrowWithLifetime<'a> for BorrowedValue<'static> {
+    type Borrowed = BorrowedValue<'a>;
+}
+
+/// A JSON buffer bundled with the `BorrowedValue` parsed from it
+pub type JsonBundle = BorrowedWithOwner<BorrowedValue<'static>, Vec<u8>>;
+
+impl JsonBundle {
+    /// Parses `buffer` in place. If parsing fails, the error is returned along with the buffer,
+    /// whose contents may have been modified by the parser.
+    pub fn parse(buffer: Vec<u8>) -> Result<Self, (simd_json::Error, Vec<u8>)> {
+        RefMutWithOwner::new(buffer)
+            .try_map::<BorrowedValue<'static>, _, _>(|bytes, _| simd_json::to_borrowed_value(bytes))
+    }
+}
@@ -0,0 +1,168 @@
+use crate::BorrowedWithOwner;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::{Chain, Enumerate};
+use core::ops::Range;
+use core::slice;
+use stable_deref_trait::{CloneStableDeref, StableDeref};
+#[cfg(feature = "std")]
+use std::io;
+
+impl<T, O> BorrowedWithOwner<&'static [T], O>
+where
+    T: 'static,
+    O: StableDeref,
+{
+    /// Narrows the borrowed slice down to `range`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds of the borrowed slice.
+    #[track_caller]
+    pub fn subslice(self, range: Range<usize>) -> Self {
+        let len = self.borrowed().len();
+        if range.start > range.end || range.end > len {
+            panic!(
+                "subslice range {:?} out of bounds for slice of length {}",
+                range, len
+            );
+        }
+
+        self.map(|slice, _| &slice[range])
+    }
+
+    /// Narrows the sorted borrowed slice down to the elements starting at its partition point,
+    /// i.e. the elements for which `predicate` returns `false`. As with `slice::partition_point`,
+    /// `predicate` must return `true` for a prefix of the slice and `false` for the rest.
+    pub fn binary_search_range<P>(self, predicate: P) -> Self
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let start = self.borrowed().partition_point(predicate);
+        self.map(|slice, _| &slice[start..])
+    }
+
+    /// Calls `f` with an `enumerate()` iterator over the borrowed slice. The iterator borrows
+    /// `self`, so it's scoped to the closure rather than returned.
+    pub fn with_enumerate<R, F>(&self, f: F) -> R
+    where
+        F: for<'a> FnOnce(Enumerate<slice::Iter<'a, T>>) -> R,
+    {
+        f(self.borrowed().iter().enumerate())
+    }
+
+    /// Calls `f` with an iterator over the overlapping windows of length `size` in the borrowed
+    /// slice. The windows borrow `self`, so the iterator is scoped to the closure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[track_caller]
+    pub fn with_windows<R, F>(&self, size: usize, f: F) -> R
+    where
+        F: for<'a> FnOnce(slice::Windows<'a, T>) -> R,
+    {
+        if size == 0 {
+            panic!("window size must be non-zero");
+        }
+
+        f(self.borrowed().windows(size))
+    }
+
+    /// Calls `f` with an iterator over the borrowed slice as if it were rotated left by `mid`,
+    /// i.e. `slice[mid..]` followed by `slice[..mid]`, without mutating the owner
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the slice.
+    #[track_caller]
+    pub fn with_rotated<R, F>(&self, mid: usize, f: F) -> R
+    where
+        F: for<'a> FnOnce(Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>) -> R,
+    {
+        let (front, back) = self.borrowed().split_at(mid);
+        f(back.iter().chain(front))
+    }
+
+    /// Copies the borrowed slice into a `Vec` with consecutive duplicates removed (see
+    /// `Vec::dedup`), and drops the owner. If the slice is sorted, the result has no duplicates.
+    #[cfg(feature = "alloc")]
+    pub fn dedup_to_owned(self) -> Vec<T>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut deduped = Vec::<T>::new();
+        for item in *self.borrowed() {
+            if deduped.last() != Some(item) {
+                deduped.push(item.clone());
+            }
+        }
+        deduped
+    }
+}
+
+impl<T, O> BorrowedWithOwner<&'static [T], O>
+where
+    T: 'static,
+    O: CloneStableDeref,
+{
+    /// Splits the borrowed slice at `mid` (see `slice::split_at`), returning bundles of the two
+    /// halves, which share the owner
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the slice.
+    #[track_caller]
+    pub fn partition_at(self, mid: usize) -> (Self, Self) {
+        let len = self.borrowed().len();
+        if mid > len {
+            panic!("mid {} out of bounds for slice of length {}", mid, len);
+        }
+
+        self.map_split(|slice, _| slice.split_at(mid))
+    }
+}
+
+impl<O> BorrowedWithOwner<&'static mut [u8], O>
+where
+    O: StableDeref,
+{
+    /// Removes the first byte from the borrowed slice and returns an `&mut`-reference to it,
+    /// or `None` if the slice is empty
+    pub fn split_first_mut_borrowed(&mut self) -> Option<&mut u8> {
+        let borrowed = self.borrowed_mut();
+        let (first, rest) = core::mem::take(borrowed).split_first_mut()?;
+        *borrowed = rest;
+        Some(first)
+    }
+
+    /// Copies `bytes` to the start of the borrowed slice, and advances the slice past them.
+    /// If the slice is too short, nothing is written and an error of kind `WriteZero` is returned.
+    #[cfg(feature = "std")]
+    pub fn write_bytes_borrowed(&mut self, bytes: &[u8]) -> io::Result<()> {
+        let borrowed = self.borrowed_mut();
+        if borrowed.len() < bytes.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "not enough space left in the borrowed slice",
+            ));
+        }
+
+        let (head, rest) = core::mem::take(borrowed).split_at_mut(bytes.len());
+        head.copy_from_slice(bytes);
+        *borrowed = rest;
+        Ok(())
+    }
+
+    /// Writes `value` as big-endian bytes with `write_bytes_borrowed`
+    #[cfg(feature = "std")]
+    pub fn write_u16_be_borrowed(&mut self, value: u16) -> io::Result<()> {
+        self.write_bytes_borrowed(&value.to_be_bytes())
+    }
+
+    /// Writes `value` as big-endian bytes with `write_bytes_borrowed`
+    #[cfg(feature = "std")]
+    pub fn write_u32_be_borrowed(&mut self, value: u32) -> io::Result<()> {
+        self.write_bytes_borrowed(&value.to_be_bytes())
+    }
+}
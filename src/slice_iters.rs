@@ -0,0 +1,45 @@
+//! `BorrowWithLifetime` impls for iterators from `std::slice`
+
+use crate::{BorrowWithLifetime, BorrowedWithOwner};
+use core::slice::{Chunks, ChunksExact, Iter, IterMut, Split, Windows};
+use stable_deref_trait::StableDeref;
+
+impl<'a, T, P> BorrowWithLifetime<'a> for Split<'static, T, P>
+where
+    T: 'static,
+    P: FnMut(&T) -> bool + 'a,
+{
+    type Borrowed = Split<'a, T, P>;
+}
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for Iter<'static, T> {
+    type Borrowed = Iter<'a, T>;
+}
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for IterMut<'static, T> {
+    type Borrowed = IterMut<'a, T>;
+}
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for Chunks<'static, T> {
+    type Borrowed = Chunks<'a, T>;
+}
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for Windows<'static, T> {
+    type Borrowed = Windows<'a, T>;
+}
+
+impl<'a, T: 'static> BorrowWithLifetime<'a> for ChunksExact<'static, T> {
+    type Borrowed = ChunksExact<'a, T>;
+}
+
+impl<T, O> BorrowedWithOwner<ChunksExact<'static, T>, O>
+where
+    T: 'static,
+    O: StableDeref,
+{
+    /// Returns the elements left over at the end of the slice that don't fit in a chunk
+    /// (see `ChunksExact::remainder`)
+    pub fn remainder_borrowed(&self) -> &[T] {
+        self.borrowed().remainder()
+    }
+}
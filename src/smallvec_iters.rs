@@ -0,0 +1,26 @@
+//! `BorrowWithLifetime` impls for iterators from `smallvec`
+//!
+//! `SmallVec` stores its first few elements inline, so it isn't `StableDeref` and can't be used
+//! as an owner directly. Use `Box<SmallVec<A>>` instead.
+
+use crate::{BorrowWithLifetime, BorrowedWithOwner};
+use smallvec::{Array, Drain, SmallVec};
+use stable_deref_trait::StableDeref;
+
+impl<'a, A> BorrowWithLifetime<'a> for Drain<'static, A>
+where
+    A: Array + 'static,
+{
+    type Borrowed = Drain<'a, A>;
+}
+
+impl<A, O> BorrowedWithOwner<&'static mut SmallVec<A>, O>
+where
+    A: Array + 'static,
+    O: StableDeref,
+{
+    /// Drains all of the elements out of the borrowed `SmallVec`, keeping its owner alive
+    pub fn drain_with_owner(self) -> BorrowedWithOwner<Drain<'static, A>, O> {
+        self.map(|v, _| v.drain(..))
+    }
+}
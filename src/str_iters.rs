@@ -0,0 +1,60 @@
+//! `BorrowWithLifetime` impls for iterators from `std::str`, along with aliases for bundles of them
+
+use crate::{BorrowWithLifetime, BorrowedWithOwner};
+use core::str::{
+    Bytes, CharIndices, Chars, EncodeUtf16, Lines, Split, SplitAsciiWhitespace, SplitWhitespace,
+};
+
+impl<'a> BorrowWithLifetime<'a> for Chars<'static> {
+    type Borrowed = Chars<'a>;
+}
+
+impl<'a> BorrowWithLifetime<'a> for CharIndices<'static> {
+    type Borrowed = CharIndices<'a>;
+}
+
+impl<'a> BorrowWithLifetime<'a> for Bytes<'static> {
+    type Borrowed = Bytes<'a>;
+}
+
+impl<'a> BorrowWithLifetime<'a> for Lines<'static> {
+    type Borrowed = Lines<'a>;
+}
+
+impl<'a> BorrowWithLifetime<'a> for SplitWhitespace<'static> {
+    type Borrowed = SplitWhitespace<'a>;
+}
+
+impl<'a> BorrowWithLifetime<'a> for SplitAsciiWhitespace<'static> {
+    type Borrowed = SplitAsciiWhitespace<'a>;
+}
+
+impl<'a> BorrowWithLifetime<'a> for EncodeUtf16<'static> {
+    type Borrowed = EncodeUtf16<'a>;
+}
+
+impl<'a> BorrowWithLifetime<'a> for Split<'static, char> {
+    type Borrowed = Split<'a, char>;
+}
+
+impl<'a> BorrowWithLifetime<'a> for Split<'static, &'static str> {
+    type Borrowed = Split<'a, &'static str>;
+}
+
+/// A bundle of an owner and a `Chars` iterator over it
+pub type CharsWithOwner<O> = BorrowedWithOwner<Chars<'static>, O>;
+
+/// A bundle of an owner and a `CharIndices` iterator over it
+pub type CharIndicesWithOwner<O> = BorrowedWithOwner<CharIndices<'static>, O>;
+
+/// A bundle of an owner and a `Bytes` iterator over it
+pub type BytesWithOwner<O> = BorrowedWithOwner<Bytes<'static>, O>;
+
+/// A bundle of an owner and a `Lines` iterator over it
+pub type LinesWithOwner<O> = BorrowedWithOwner<Lines<'static>, O>;
+
+/// A bundle of an owner and a `SplitWhitespace` iterator over it
+pub type SplitWhitespaceWithOwner<O> = BorrowedWithOwner<SplitWhitespace<'static>, O>;
+
+/// A bundle of an owner and a `SplitAsciiWhitespace` iterator over it
+pub type SplitAsciiWhitespaceWithOwner<O> = BorrowedWithOwner<SplitAsciiWhitespace<'static>, O>;
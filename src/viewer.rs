@@ -0,0 +1,43 @@
+use crate::{BorrowWithLifetime, BorrowedWithOwner, Lifetime};
+use stable_deref_trait::CloneStableDeref;
+
+/// A factory for bundles that share the owner of a `BorrowedWithOwner`
+///
+/// Each call to `.view()` clones the stored bundle (cheaply, since the owner is
+/// `CloneStableDeref`) and maps the clone to a new borrowed value.
+pub struct Viewer<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    O: CloneStableDeref,
+{
+    bundle: BorrowedWithOwner<B, O>,
+}
+
+impl<B, O> Viewer<B, O>
+where
+    B: for<'a> BorrowWithLifetime<'a>,
+    for<'a> <B as BorrowWithLifetime<'a>>::Borrowed: Clone,
+    O: CloneStableDeref,
+{
+    /// Creates a `Viewer` that produces views of `bundle`
+    pub fn new(bundle: BorrowedWithOwner<B, O>) -> Self {
+        Self { bundle }
+    }
+
+    /// Returns the bundle that this `Viewer` produces views of
+    pub fn into_inner(self) -> BorrowedWithOwner<B, O> {
+        self.bundle
+    }
+
+    /// Clones the stored bundle and maps it with `f`
+    pub fn view<B2, F>(&self, f: F) -> BorrowedWithOwner<B2, O>
+    where
+        B2: for<'a> BorrowWithLifetime<'a>,
+        F: for<'a> FnOnce(
+            <B as BorrowWithLifetime<'a>>::Borrowed,
+            Lifetime<'a>,
+        ) -> <B2 as BorrowWithLifetime<'a>>::Borrowed,
+    {
+        self.bundle.clone().map(f)
+    }
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use borrowed_with_owner::RefWithOwner;
+
+fn fuzz_target(data: &[u8]) -> arbitrary::Result<()> {
+    let mut u = Unstructured::new(data);
+
+    let s = RefWithOwner::<String>::arbitrary(&mut u)?;
+    let len = s.borrowed().len();
+    assert_eq!(s.into_owner().len(), len);
+
+    let bytes = RefWithOwner::<Vec<u8>>::arbitrary(&mut u)?;
+    let copy = bytes.borrowed().to_vec();
+    assert_eq!(bytes.into_owner(), copy);
+
+    Ok(())
+}
+
+#[test]
+fn arbitrary_bundles() {
+    let inputs: [&[u8]; 4] = [
+        b"",
+        b"\x03abc\x02\xff\x00",
+        b"hello, world! this is some fuzz input",
+        &[0xff; 64],
+    ];
+
+    for input in inputs {
+        fuzz_target(input).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "async-stream")]
+
+use async_stream::stream;
+use borrowed_with_owner::RefWithOwner;
+use futures_core::Stream;
+use std::future::poll_fn;
+use std::pin::Pin;
+
+async fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+    let mut items = Vec::new();
+    while let Some(item) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+        items.push(item);
+    }
+    items
+}
+
+#[tokio::test]
+async fn stream_parsed_lines() {
+    let buffer = b"1\n2\nnot a number\n40\n".to_vec();
+    let numbers = RefWithOwner::new(buffer).map_stream(|bytes, _| {
+        Box::pin(stream! {
+            for line in bytes.split(|&b| b == b'\n').filter(|line| !line.is_empty()) {
+                tokio::task::yield_now().await;
+                yield std::str::from_utf8(line).ok().and_then(|line| line.parse::<u32>().ok());
+            }
+        })
+    });
+
+    let numbers = tokio::spawn(collect(numbers)).await.unwrap();
+    assert_eq!(numbers, [Some(1), Some(2), None, Some(40)]);
+}
+
+#[tokio::test]
+async fn local_stream_with_shared_state() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let seen = Rc::new(Cell::new(0));
+    let words = RefWithOwner::new(String::from("a b c")).map_local_stream({
+        let seen = seen.clone();
+        move |s, _| {
+            Box::pin(stream! {
+                for word in s.split(' ') {
+                    seen.set(seen.get() + 1);
+                    tokio::task::yield_now().await;
+                    yield word.len();
+                }
+            })
+        }
+    });
+
+    assert_eq!(collect(words).await, [1, 1, 1]);
+    assert_eq!(seen.get(), 3);
+}
@@ -0,0 +1,55 @@
+use borrowed_with_owner::RefWithOwner;
+
+#[test]
+fn map_impl_iter_filter() {
+    let mut evens = RefWithOwner::new(Box::new(vec![1, 2, 3, 4, 5, 6]))
+        .map_impl_iter(|v, _| Box::new(v.iter().filter(|n| **n % 2 == 0)));
+
+    let first = evens.borrowed_mut().next();
+    assert_eq!(first, Some(&2));
+    assert_eq!(evens.borrowed_mut().collect::<Vec<_>>(), [&4, &6]);
+}
+
+#[test]
+fn size_hint_forwarded() {
+    let mut strings = RefWithOwner::new(vec![String::from("a"), "b".into(), "c".into()])
+        .map_impl_iter(|v, _| Box::new(v.iter().map(String::as_str)));
+    assert_eq!(strings.size_hint_borrowed(), (3, Some(3)));
+
+    strings.borrowed_mut().next();
+    assert_eq!(strings.size_hint_borrowed(), (2, Some(2)));
+
+    let evens = RefWithOwner::new(vec![1, 2, 3, 4])
+        .map_impl_iter(|v, _| Box::new(v.iter().filter(|n| **n % 2 == 0)));
+    assert_eq!(evens.size_hint_borrowed(), (0, Some(4)));
+}
+
+struct Order {
+    customer: String,
+    items: Vec<(String, u32)>,
+}
+
+#[test]
+fn boxed_dyn_display() {
+    use borrowed_with_owner::BoxedUnsized;
+    use std::fmt::Display;
+
+    let order = Box::new(Order {
+        customer: "Ada".into(),
+        items: vec![("tea".into(), 2), ("cake".into(), 1)],
+    });
+
+    let first_item =
+        RefWithOwner::new(order).map::<BoxedUnsized<dyn Display + Send>, _>(|order, _| {
+            // the first item's name is borrowed from the owner, while the fallback is owned
+            match order.items.first() {
+                Some((name, _)) => Box::new(name),
+                None => Box::new(format!("nothing for {}", order.customer)),
+            }
+        });
+
+    let summary = std::thread::spawn(move || first_item.borrowed().to_string())
+        .join()
+        .unwrap();
+    assert_eq!(summary, "tea");
+}
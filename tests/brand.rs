@@ -0,0 +1,19 @@
+use borrowed_with_owner::RefWithOwner;
+use std::sync::Arc;
+
+#[test]
+fn zip_same_brand() {
+    let text = RefWithOwner::new(Arc::<str>::from("left|right"));
+
+    let zipped = text.branded(|text| {
+        let left = text.view::<&'static str, _>(|s, _| s.split('|').next().unwrap());
+        let right = text.view::<&'static str, _>(|s, _| s.split('|').nth(1).unwrap());
+        left.zip(right).into_inner()
+    });
+
+    std::thread::spawn(move || {
+        assert_eq!(*zipped.borrowed(), ("left", "right"));
+    })
+    .join()
+    .unwrap();
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "bumpalo")]
+
+use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+use bumpalo::Bump;
+
+struct SliceOfStrs;
+
+impl<'a> BorrowWithLifetime<'a> for SliceOfStrs {
+    type Borrowed = &'a [&'a str];
+}
+
+#[test]
+fn map_arena() {
+    let titles = RefWithOwner::new(Box::new(Bump::new())).map_arena::<SliceOfStrs, _>(|bump, _| {
+        bump.alloc_slice_fill_iter(
+            (0..3).map(|i| bumpalo::format!(in bump, "Mambo number {}", i).into_bump_str()),
+        )
+    });
+
+    assert_eq!(
+        *titles.borrowed(),
+        ["Mambo number 0", "Mambo number 1", "Mambo number 2"]
+    );
+    assert!(titles.into_owner().allocated_bytes() > 0);
+}
@@ -0,0 +1,16 @@
+#![cfg(feature = "bytemuck")]
+
+use borrowed_with_owner::RefWithOwner;
+use bytemuck::TransparentWrapper;
+
+#[repr(transparent)]
+struct Meters(u32);
+
+unsafe impl TransparentWrapper<u32> for Meters {}
+
+#[test]
+fn cast_ref() {
+    let distance = RefWithOwner::new(Box::new(42u32)).cast_ref::<Meters>();
+    assert_eq!(distance.borrowed().0, 42);
+    assert_eq!(*distance.into_owner(), 42);
+}
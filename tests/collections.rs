@@ -0,0 +1,38 @@
+use borrowed_with_owner::{BorrowedWithOwner, RefMutWithOwner, RefWithOwner};
+use std::collections::{btree_map, BTreeMap};
+use std::sync::Arc;
+
+#[test]
+fn btree_map_range() {
+    let map = (0..10)
+        .map(|i| (format!("key{i}"), i))
+        .collect::<BTreeMap<String, u32>>();
+
+    let range: BorrowedWithOwner<btree_map::Range<'static, String, u32>, _> =
+        RefWithOwner::new(Arc::new(map))
+            .map(|map, _| map.range("key3".to_owned().."key6".to_owned()));
+    let cached = range.clone();
+
+    let mut range = range;
+    let values = range.borrowed_mut().map(|(_, v)| *v).collect::<Vec<_>>();
+    assert_eq!(values, [3, 4, 5]);
+
+    let keys = cached
+        .borrowed()
+        .clone()
+        .map(|(k, _)| k.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(keys, ["key3", "key4", "key5"]);
+}
+
+#[test]
+fn btree_map_values_mut() {
+    let map = BTreeMap::from([("a", 1), ("b", 2)]);
+    let mut values = RefMutWithOwner::new(Box::new(map))
+        .map::<btree_map::ValuesMut<'static, &str, i32>, _>(|map, _| map.values_mut());
+    values.borrowed_mut().for_each(|v| *v *= 10);
+
+    let map = values.into_owner();
+    assert_eq!(map["a"], 10);
+    assert_eq!(map["b"], 20);
+}
@@ -0,0 +1,11 @@
+// the expected compiler output depends on which features are enabled
+#![cfg(feature = "std")]
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    // having a pass case makes trybuild run `cargo build` instead of `cargo check`, which is
+    // needed for post-monomorphization errors like the one from `new_max_size`
+    t.pass("tests/pass/*.rs");
+}
@@ -0,0 +1,37 @@
+use borrowed_with_owner::{BorrowedWithOwner, CowOwner, Either, RefWithOwner};
+use std::sync::Arc;
+
+type FirstWord = BorrowedWithOwner<&'static str, CowOwner<String>>;
+
+fn first_word(owner: CowOwner<String>) -> FirstWord {
+    RefWithOwner::new(owner).map(|s, _| s.split(' ').next().unwrap())
+}
+
+#[test]
+fn shared_or_owned() {
+    let shared = Arc::new(String::from("a b"));
+    let bundle = first_word(CowOwner::new(Either::Left(shared.clone())));
+    assert!(!bundle.owner().is_owned());
+    assert_eq!(Arc::strong_count(&shared), 2);
+    assert_eq!(*bundle, *"a");
+
+    let bundle = first_word(CowOwner::new(Either::Right(String::from("c d"))));
+    assert!(bundle.owner().is_owned());
+    assert_eq!(*bundle, *"c");
+}
+
+#[test]
+fn promote_to_owned() {
+    let shared = Arc::new(String::from("hello world"));
+    let bundle = first_word(CowOwner::new(Either::Left(shared.clone())));
+
+    let bundle = bundle.promote(|s, _| s.split(' ').next().unwrap());
+    assert!(bundle.owner().is_owned());
+    assert_eq!(Arc::strong_count(&shared), 1);
+
+    drop(shared);
+    assert_eq!(*bundle, *"hello");
+
+    let bundle = bundle.promote(|_, _| unreachable!());
+    assert_eq!(*bundle, *"hello");
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "drop_log")]
+
+use borrowed_with_owner::drop_log::{clear_drop_hook, set_drop_hook, DropLogged};
+use borrowed_with_owner::RefWithOwner;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+static LAST_NAME: Mutex<&str> = Mutex::new("");
+
+fn count_drop(name: &'static str) {
+    DROPS.fetch_add(1, Ordering::SeqCst);
+    *LAST_NAME.lock().unwrap() = name;
+}
+
+#[test]
+fn drop_hook() {
+    set_drop_hook(count_drop);
+
+    let bundle = RefWithOwner::new(DropLogged::new(String::from("logged")))
+        .map::<&'static str, _>(|s, _| &s[..3]);
+    assert_eq!(*bundle.borrowed(), "log");
+    assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+
+    drop(bundle);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+    assert_eq!(*LAST_NAME.lock().unwrap(), "alloc::string::String");
+
+    clear_drop_hook();
+    drop(RefWithOwner::new(DropLogged::new(vec![1])));
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+}
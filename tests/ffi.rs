@@ -0,0 +1,41 @@
+#![cfg(feature = "std")]
+
+use borrowed_with_owner::RefWithOwner;
+
+#[test]
+fn cstr_ref() {
+    let cstr = RefWithOwner::new(b"hello\0".to_vec())
+        .cstr_ref()
+        .map_err(|(err, _)| err)
+        .unwrap();
+
+    std::thread::spawn(move || {
+        assert_eq!(cstr.borrowed().to_str(), Ok("hello"));
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn cstr_ref_missing_nul() {
+    let (_, bytes) = RefWithOwner::new(b"hello".to_vec())
+        .cstr_ref()
+        .err()
+        .unwrap();
+    assert_eq!(bytes.into_owner(), b"hello");
+}
+
+#[test]
+fn os_str_bytes() {
+    use std::ffi::OsString;
+
+    let os = RefWithOwner::new(OsString::from("some/path.txt"));
+
+    std::thread::spawn(move || {
+        assert_eq!(os.as_encoded_bytes_borrowed(), b"some/path.txt");
+        #[cfg(unix)]
+        assert_eq!(os.as_bytes_borrowed(), b"some/path.txt");
+    })
+    .join()
+    .unwrap();
+}
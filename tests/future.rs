@@ -0,0 +1,85 @@
+use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+#[tokio::test]
+async fn map_store_future() {
+    let mut word_count =
+        RefWithOwner::new(String::from("the quick brown fox")).map_store_future(|s, _| {
+            Box::pin(async move {
+                tokio::task::yield_now().await;
+                s.split_whitespace().count()
+            })
+        });
+
+    let count = tokio::spawn(async move { word_count.borrowed_mut().await })
+        .await
+        .unwrap();
+
+    assert_eq!(count, 4);
+}
+
+#[tokio::test]
+async fn map_store_local_future() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut words = RefWithOwner::new(String::from("a b c")).map_store_local_future({
+        let log = log.clone();
+        move |s, _| {
+            Box::pin(async move {
+                for word in s.split(' ') {
+                    tokio::task::yield_now().await;
+                    log.borrow_mut().push(word.to_owned());
+                }
+                s.len()
+            })
+        }
+    });
+
+    assert_eq!(words.borrowed_mut().await, 5);
+    assert_eq!(*log.borrow(), ["a", "b", "c"]);
+}
+
+struct CountChars<'a> {
+    s: &'a str,
+    yielded: bool,
+    _pinned: PhantomPinned,
+}
+
+impl Future for CountChars<'_> {
+    type Output = usize;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        // no fields are moved out
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.yielded {
+            Poll::Ready(this.s.chars().count())
+        } else {
+            this.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+impl<'a> BorrowWithLifetime<'a> for CountChars<'static> {
+    type Borrowed = CountChars<'a>;
+}
+
+#[tokio::test]
+async fn poll_pinned_borrowed_future() {
+    let bundle =
+        RefWithOwner::new(String::from("héllo")).map::<CountChars<'static>, _>(|s, _| CountChars {
+            s,
+            yielded: false,
+            _pinned: PhantomPinned,
+        });
+    let mut bundle = Box::pin(bundle);
+
+    let count = std::future::poll_fn(|cx| bundle.as_mut().pinned().poll(cx)).await;
+    assert_eq!(count, 5);
+}
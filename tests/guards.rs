@@ -0,0 +1,70 @@
+#![cfg(feature = "std")]
+
+use borrowed_with_owner::BorrowedWithOwner;
+use std::cell::{RefCell, RefMut};
+use std::rc::Rc;
+
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+#[test]
+fn borrow_mut_field() {
+    let config = Rc::new(RefCell::new(Config {
+        name: "server".into(),
+        retries: 0,
+    }));
+
+    let mut retries =
+        BorrowedWithOwner::<RefMut<'static, u32>, _>::borrow_mut_field(config.clone(), |config| {
+            &mut config.retries
+        });
+
+    **retries.borrowed_mut() += 3;
+    assert!(config.try_borrow().is_err());
+
+    drop(retries);
+
+    let config = config.borrow();
+    assert_eq!(config.retries, 3);
+    assert_eq!(config.name, "server");
+}
+
+#[test]
+fn guarded_mutex() {
+    use borrowed_with_owner::{Guarded, RefMutWithOwner, RefWithOwner};
+    use std::sync::{Arc, Mutex, MutexGuard};
+
+    let data = Arc::new(Mutex::new(vec![1, 2, 3]));
+
+    let mut locked =
+        RefWithOwner::new(data.clone())
+            .map::<Guarded<MutexGuard<'static, Vec<i32>>, &'static mut [i32]>, _>(|mutex, _| {
+                RefMutWithOwner::new(mutex.lock().unwrap()).map(|v, _| &mut v[1..])
+            });
+
+    for x in locked.borrowed_mut().borrowed_mut().iter_mut() {
+        *x *= 10;
+    }
+    assert!(data.try_lock().is_err());
+
+    drop(locked);
+
+    assert_eq!(*data.lock().unwrap(), [1, 20, 30]);
+}
+
+#[test]
+fn lock_borrowed_twice() {
+    use borrowed_with_owner::RefWithOwner;
+    use std::sync::{Arc, Mutex};
+
+    let counter = RefWithOwner::new(Arc::new(Mutex::new(0)));
+
+    *counter.lock_borrowed().unwrap() += 1;
+    *counter.lock_borrowed().unwrap() += 1;
+
+    std::thread::spawn(move || assert_eq!(*counter.lock_borrowed().unwrap(), 2))
+        .join()
+        .unwrap();
+}
@@ -0,0 +1,16 @@
+#![cfg(feature = "hashbrown")]
+
+use borrowed_with_owner::RefWithOwner;
+use hashbrown::hash_map::Values;
+use hashbrown::HashMap;
+
+#[test]
+fn bundled_hashbrown_values() {
+    let map: HashMap<&str, i32> = [("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+    let mut values = RefWithOwner::new(Box::new(map))
+        .map::<Values<'static, &'static str, i32>, _>(|map, _| map.values());
+
+    let mut collected = values.borrowed_mut().copied().collect::<Vec<_>>();
+    collected.sort();
+    assert_eq!(collected, [1, 2, 3]);
+}
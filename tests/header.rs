@@ -0,0 +1,78 @@
+#![cfg(feature = "zerocopy")]
+
+use borrowed_with_owner::{HeaderError, RefWithOwner};
+use zerocopy::{FromBytes, Immutable, KnownLayout};
+
+#[derive(FromBytes, KnownLayout, Immutable)]
+#[repr(C)]
+struct Header {
+    magic: [u8; 4],
+    version: u8,
+    flags: u8,
+    len: [u8; 2],
+}
+
+#[test]
+fn header_ref() {
+    let bytes = b"WXYZ\x02\x01\x00\x10payload".to_vec();
+    let header = RefWithOwner::new(bytes)
+        .map::<&'static [u8], _>(|b, _| b)
+        .header_ref::<Header>()
+        .map_err(|(err, _)| err)
+        .unwrap();
+
+    std::thread::spawn(move || {
+        let header = header.borrowed();
+        assert_eq!(&header.magic, b"WXYZ");
+        assert_eq!(header.version, 2);
+        assert_eq!(header.flags, 1);
+        assert_eq!(u16::from_be_bytes(header.len), 16);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn header_ref_too_short() {
+    let (err, bytes) = RefWithOwner::new(b"WXYZ".to_vec())
+        .map::<&'static [u8], _>(|b, _| b)
+        .header_ref::<Header>()
+        .err()
+        .unwrap();
+
+    assert_eq!(err, HeaderError::TooShort);
+    assert_eq!(*bytes.borrowed(), b"WXYZ");
+}
+
+#[test]
+fn try_map_cast() {
+    use borrowed_with_owner::CastError;
+
+    // 8 bytes of `u32`s, which the allocator aligns for `u32` in practice
+    let bytes = RefWithOwner::new(vec![1u8, 0, 0, 0, 2, 0, 0, 0]);
+    let words = bytes
+        .try_map_cast::<u32, _>(|words| words.iter().all(|w| *w != 0))
+        .map_err(|(err, _)| err)
+        .unwrap();
+    assert_eq!(
+        *words.borrowed(),
+        [u32::from_le_bytes([1, 0, 0, 0]), 2u32.to_le()]
+    );
+
+    let misaligned = RefWithOwner::new(words.into_owner()).subslice(1..5);
+    let (err, misaligned) = misaligned.try_map_cast::<u32, _>(|_| true).err().unwrap();
+    assert_eq!(err, CastError::Misaligned);
+
+    let (err, _) = misaligned
+        .subslice(1..4)
+        .try_map_cast::<u16, _>(|_| true)
+        .err()
+        .unwrap();
+    assert_eq!(err, CastError::Size);
+
+    let (err, _) = RefWithOwner::new(vec![0u8; 4])
+        .try_map_cast::<u32, _>(|words| words[0] != 0)
+        .err()
+        .unwrap();
+    assert_eq!(err, CastError::Invalid);
+}
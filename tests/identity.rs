@@ -0,0 +1,50 @@
+use borrowed_with_owner::{ByOwnerIdentity, ByOwnerOrder, RefWithOwner};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[test]
+fn hash_by_owner_identity() {
+    let first = RefWithOwner::new(Arc::<str>::from("hello world"));
+    let hello = first.clone().map::<&'static str, _>(|s, _| &s[..5]);
+    let world = first.clone().map::<&'static str, _>(|s, _| &s[6..]);
+    let clone = first.clone();
+    let other = RefWithOwner::new(Arc::<str>::from("hello world"));
+
+    let mut set = HashSet::new();
+    assert!(set.insert(ByOwnerIdentity(first)));
+    assert!(!set.insert(ByOwnerIdentity(clone)));
+    assert!(!set.insert(ByOwnerIdentity(hello)));
+    assert!(!set.insert(ByOwnerIdentity(world)));
+    assert!(set.insert(ByOwnerIdentity(other)));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn sort_and_search_by_owner_order() {
+    let owners = ["a", "b", "c", "d"].map(Arc::<str>::from);
+    let mut bundles = owners
+        .iter()
+        .rev()
+        .map(|owner| ByOwnerOrder(RefWithOwner::new(owner.clone())))
+        .collect::<Vec<_>>();
+    bundles.sort();
+
+    assert!(bundles
+        .windows(2)
+        .all(|pair| pair[0].0.owner_ptr().cast::<()>() < pair[1].0.owner_ptr().cast::<()>()));
+
+    for owner in &owners {
+        let ptr = Arc::as_ptr(owner).cast::<()>();
+        let index = bundles
+            .binary_search_by_key(&ptr, |bundle| bundle.0.owner_ptr().cast())
+            .unwrap();
+        assert_eq!(*bundles[index].0, **owner);
+    }
+
+    let needle =
+        ByOwnerOrder(RefWithOwner::new(owners[2].clone()).map::<&'static str, _>(|s, _| s));
+    assert!(bundles.binary_search(&needle).is_ok());
+
+    let stranger = ByOwnerOrder(RefWithOwner::new(Arc::<str>::from("c")));
+    assert!(bundles.binary_search(&stranger).is_err());
+}
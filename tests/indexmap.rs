@@ -0,0 +1,29 @@
+#![cfg(feature = "indexmap")]
+
+use borrowed_with_owner::RefWithOwner;
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+#[test]
+fn bundled_index_map_values() {
+    let map: IndexMap<String, i32> = [("a", 1), ("b", 2), ("c", 3)]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+
+    let mut values = RefWithOwner::new(Arc::new(map)).values_with_owner();
+
+    let sum = std::thread::spawn(move || values.borrowed_mut().sum::<i32>())
+        .join()
+        .unwrap();
+    assert_eq!(sum, 6);
+}
+
+#[test]
+fn bundled_index_map_iter() {
+    let map: IndexMap<&str, i32> = [("b", 2), ("a", 1)].into_iter().collect();
+    let mut iter = RefWithOwner::new(Box::new(map)).iter_with_owner();
+
+    let keys = iter.borrowed_mut().map(|(k, _)| *k).collect::<Vec<_>>();
+    assert_eq!(keys, ["b", "a"]);
+}
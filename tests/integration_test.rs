@@ -25,3 +25,751 @@ fn it_works() {
     .join()
     .unwrap();
 }
+
+#[test]
+fn try_with_borrowed() {
+    let good = RefWithOwner::new(String::from("42"));
+    assert_eq!(good.try_with_borrowed(|s| s.parse::<i32>()), Ok(42));
+
+    let bad = RefWithOwner::new(String::from("forty-two"));
+    let parse = |s: &str| -> Result<i32, std::num::ParseIntError> {
+        let n = s.trim().parse::<i32>()?;
+        Ok(n * 2)
+    };
+    assert!(bad.try_with_borrowed(|s| parse(s)).is_err());
+}
+
+struct ConcatView;
+
+impl<'a> BorrowWithLifetime<'a> for ConcatView {
+    type Borrowed = Vec<&'a str>;
+}
+
+#[test]
+fn vec_of_bundles_as_owner() {
+    let first = RefWithOwner::new(String::from("Hello, world"))
+        .map::<&'static str, _>(|s, _| s.split(", ").next().unwrap());
+    let second = RefWithOwner::new(String::from("my name is Michael"))
+        .map::<&'static str, _>(|s, _| &s[s.len() - 7..]);
+
+    let parent = RefWithOwner::new(vec![first, second]).map::<ConcatView, _>(|children, _| {
+        children.iter().map(|child| *child.borrowed()).collect()
+    });
+
+    std::thread::spawn(move || {
+        assert_eq!(parent.borrowed().concat(), "HelloMichael");
+    })
+    .join()
+    .unwrap();
+}
+
+#[derive(Debug, PartialEq)]
+struct Overrun;
+
+#[test]
+fn step_cursor() {
+    let mut cursor = RefWithOwner::new(vec![1u8, 2, 3]).map::<&'static [u8], _>(|v, _| v);
+
+    let advance = |rest: &mut &[u8], n: usize| {
+        if rest.len() < n {
+            return Err(Overrun);
+        }
+        let r: &[u8] = rest;
+        *rest = &r[n..];
+        Ok(())
+    };
+
+    assert_eq!(cursor.step(|rest| advance(rest, 2)), Ok(()));
+    assert_eq!(*cursor.borrowed(), [3]);
+    assert_eq!(cursor.step(|rest| advance(rest, 2)), Err(Overrun));
+    assert_eq!(*cursor.borrowed(), [3]);
+}
+
+#[test]
+fn retain_borrowed() {
+    use borrowed_with_owner::RefMutWithOwner;
+
+    let mut evens = RefMutWithOwner::new(Box::new(vec![1, 2, 3, 4, 5, 6]));
+    evens.retain_borrowed(|x| x % 2 == 0);
+
+    assert_eq!(*evens.into_owner(), [2, 4, 6]);
+}
+
+#[test]
+fn bundled_once_lock() {
+    use std::sync::{Arc, OnceLock};
+
+    struct Lazy {
+        greeting: OnceLock<String>,
+    }
+
+    let lazy = Arc::new(Lazy {
+        greeting: OnceLock::new(),
+    });
+    let cell = RefWithOwner::new(lazy).map::<&'static OnceLock<String>, _>(|l, _| &l.greeting);
+    let cell2 = cell.clone();
+
+    std::thread::spawn(move || {
+        cell2.borrowed().get_or_init(|| "hello".into());
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(cell.borrowed().get().map(String::as_str), Some("hello"));
+}
+
+#[test]
+fn clone_outlives_original() {
+    use std::rc::Rc;
+
+    let original =
+        RefWithOwner::new(Rc::new(String::from("shared"))).map::<&'static str, _>(|s, _| &s[1..]);
+    let clone = original.clone();
+    let ptr = original.borrowed().as_ptr();
+
+    drop(original);
+
+    assert_eq!(clone.borrowed().as_ptr(), ptr);
+    assert_eq!(*clone.borrowed(), "hared");
+}
+
+struct Len;
+
+impl<'a> BorrowWithLifetime<'a> for Len {
+    type Borrowed = usize;
+}
+
+#[test]
+fn swap_owner() {
+    let mut len = RefWithOwner::new(String::from("abc")).map::<Len, _>(|s, _| s.len());
+    let old = len.swap_owner(String::from("defg"));
+    assert_eq!(old, "abc");
+    assert_eq!(*len.borrowed(), 3);
+    assert_eq!(len.into_owner(), "defg");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn bundled_hash_map() {
+    use borrowed_with_owner::RefMutWithOwner;
+    use std::collections::HashMap;
+
+    let mut map = RefMutWithOwner::new(Box::new(HashMap::new()));
+    assert_eq!(map.insert_borrowed("a", 1), None);
+    assert_eq!(map.insert_borrowed("b", 2), None);
+    assert_eq!(map.insert_borrowed("a", 3), Some(1));
+    assert_eq!(map.remove_borrowed("b"), Some(2));
+
+    let map = map.into_owner();
+    assert_eq!(map.len(), 1);
+    assert_eq!(map["a"], 3);
+}
+
+#[test]
+fn new_send() {
+    let s = RefWithOwner::new_send(std::sync::Arc::new(String::from("sent")));
+
+    std::thread::spawn(move || {
+        assert_eq!(s.borrowed().as_str(), "sent");
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn borrowed_any() {
+    let len = RefWithOwner::new(String::from("abc")).map::<Len, _>(|s, _| s.len());
+    let unit = RefWithOwner::new(vec![1, 2]).map::<(), _>(|_, _| ());
+
+    let anys = [len.borrowed_any(), unit.borrowed_any()];
+    assert_eq!(anys[0].downcast_ref::<usize>(), Some(&3));
+    assert!(anys[0].downcast_ref::<()>().is_none());
+    assert_eq!(anys[1].downcast_ref::<()>(), Some(&()));
+}
+
+#[test]
+fn phantom_borrow() {
+    use borrowed_with_owner::PhantomBorrow;
+    use std::marker::PhantomData;
+
+    let witness =
+        RefWithOwner::new(String::from("abc")).map::<PhantomBorrow<str>, _>(|_, _| PhantomData);
+
+    assert_eq!(std::mem::size_of_val(witness.borrowed()), 0);
+    let _: &PhantomData<&str> = witness.borrowed();
+    assert_eq!(witness.into_owner(), "abc");
+}
+
+#[test]
+fn views() {
+    use borrowed_with_owner::Lifetime;
+    use std::sync::Arc;
+
+    let fields = RefWithOwner::new(Arc::<str>::from("alpha,beta,gamma"));
+    let fs: [for<'a> fn(&'a str, Lifetime<'a>) -> &'a str; 3] = [
+        |s, _| s.split(',').next().unwrap(),
+        |s, _| s.split(',').nth(1).unwrap(),
+        |s, _| s.split(',').nth(2).unwrap(),
+    ];
+    let handles = fields
+        .views::<&'static str, _, 3>(fs)
+        .map(|view| std::thread::spawn(move || view.borrowed().to_uppercase()));
+
+    let results = handles.map(|h| h.join().unwrap());
+    assert_eq!(results, ["ALPHA", "BETA", "GAMMA"]);
+}
+
+#[test]
+fn map_ref() {
+    use std::sync::Arc;
+
+    let buffer = RefWithOwner::new(Arc::<[u8]>::from(&b"headerbody"[..]));
+    let header = buffer.map_ref::<&'static [u8], _>(|bytes, _| &bytes[..6]);
+    let body = buffer.map_ref::<&'static [u8], _>(|bytes, _| &bytes[6..]);
+    drop(buffer);
+
+    let header = std::thread::spawn(move || header.borrowed().to_vec());
+    let body = std::thread::spawn(move || body.borrowed().to_vec());
+    assert_eq!(header.join().unwrap(), b"header");
+    assert_eq!(body.join().unwrap(), b"body");
+}
+
+struct Person {
+    name: String,
+    age: u32,
+}
+
+struct PersonAndName;
+
+impl<'a> BorrowWithLifetime<'a> for PersonAndName {
+    type Borrowed = (&'a Person, &'a str);
+}
+
+#[test]
+fn boxed_value_as_owner() {
+    let person = Box::new(Person {
+        name: "Ada".into(),
+        age: 36,
+    });
+
+    let bundle =
+        RefWithOwner::new(person).map::<PersonAndName, _>(|person, _| (person, &person.name));
+
+    let (person, name) = *bundle.borrowed();
+    assert_eq!(person.age, 36);
+    assert_eq!(name, "Ada");
+}
+
+#[test]
+fn uppercase_bundled_str() {
+    use borrowed_with_owner::RefMutWithOwner;
+
+    let owner = Box::new(String::from(Box::<str>::from("hello, world")));
+    let mut s = RefMutWithOwner::new(owner).as_mut_str();
+    s.make_ascii_uppercase_borrowed();
+    assert_eq!(&**s.borrowed(), "HELLO, WORLD");
+
+    let mut world = s.map::<&'static mut str, _>(|s, _| &mut s[7..]);
+    world.borrowed_mut().make_ascii_lowercase();
+    assert_eq!(*world.into_owner(), "HELLO, world");
+}
+
+#[test]
+fn remap() {
+    let last_word = RefWithOwner::new(String::from("hello world"))
+        .map::<&'static str, _>(|s, _| s.rsplit(' ').next().unwrap());
+    assert_eq!(*last_word.borrowed(), "world");
+
+    let last_word = last_word.remap::<&'static str, _, _>(
+        |s| s.push_str(" again"),
+        |s, _| s.rsplit(' ').next().unwrap(),
+    );
+    assert_eq!(*last_word.borrowed(), "again");
+    assert_eq!(last_word.into_owner(), "hello world again");
+}
+
+fn first_word(s: String) -> borrowed_with_owner::BorrowedWithOwner<&'static str, String> {
+    RefWithOwner::new(s).map_checked(
+        |s, _| s.split(' ').next().unwrap(),
+        |word: &&str| assert!(!word.is_empty(), "first word is empty"),
+    )
+}
+
+#[test]
+fn map_checked() {
+    assert_eq!(*first_word("hello world".into()).borrowed(), "hello");
+}
+
+#[test]
+#[should_panic(expected = "first word is empty")]
+fn map_checked_fails() {
+    first_word(" hello".into());
+}
+
+#[test]
+fn flatten_owner() {
+    let trimmed = RefWithOwner::new(String::from("  nested bundles  "))
+        .map::<&'static str, _>(|s, _| s.trim());
+    let nested = RefWithOwner::new(trimmed).map::<&'static str, _>(|s, _| &s[..6]);
+    assert_eq!(*nested.borrowed(), "nested");
+
+    let flattened: borrowed_with_owner::BorrowedWithOwner<&'static str, String> =
+        nested.flatten_owner();
+
+    std::thread::spawn(move || {
+        assert_eq!(*flattened.borrowed(), "nested");
+        assert_eq!(flattened.into_owner(), "  nested bundles  ");
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn either_borrow() {
+    use borrowed_with_owner::{BorrowedWithOwner, Either, EitherBorrow};
+
+    type MaybeEscaped = BorrowedWithOwner<EitherBorrow<&'static str, String>, String>;
+
+    fn unescape(s: String) -> MaybeEscaped {
+        RefWithOwner::new(s).map(|s, _| {
+            if s.contains("\\n") {
+                Either::Right(s.replace("\\n", "\n"))
+            } else {
+                Either::Left(s)
+            }
+        })
+    }
+
+    let plain = unescape("no escapes".into());
+    let escaped = unescape("one\\ntwo".into());
+
+    assert_eq!(*plain.borrowed(), Either::Left("no escapes"));
+    assert_eq!(*escaped.borrowed(), Either::Right(String::from("one\ntwo")));
+}
+
+#[test]
+fn bundled_cell() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let counters = Rc::new([Cell::new(0), Cell::new(0)]);
+    let second = RefWithOwner::new(counters.clone()).map::<&'static Cell<i32>, _>(|c, _| &c[1]);
+    let second2 = second.clone();
+
+    second.borrowed().set(second.borrowed().get() + 1);
+    second2.borrowed().set(second2.borrowed().get() + 1);
+
+    assert_eq!(counters[0].get(), 0);
+    assert_eq!(counters[1].get(), 2);
+}
+
+struct Chars;
+
+impl<'a> BorrowWithLifetime<'a> for Chars {
+    type Borrowed = std::str::Chars<'a>;
+}
+
+#[test]
+fn viewer() {
+    use borrowed_with_owner::Viewer;
+    use std::sync::Arc;
+
+    let viewer = Viewer::new(RefWithOwner::new(Arc::<str>::from("key: value")));
+
+    let key = viewer.view::<&'static str, _>(|s, _| s.split(": ").next().unwrap());
+    let len = viewer.view::<Len, _>(|s, _| s.len());
+    let mut chars = viewer.view::<Chars, _>(|s, _| s.chars());
+
+    assert_eq!(*key.borrowed(), "key");
+    assert_eq!(*len.borrowed(), 10);
+    assert_eq!(chars.borrowed_mut().next_back(), Some('e'));
+    assert_eq!(&*viewer.into_inner(), "key: value");
+}
+
+#[test]
+fn bundled_atomics() {
+    use std::sync::atomic::{AtomicU8, Ordering};
+    use std::sync::Arc;
+
+    let buffer: Arc<[AtomicU8]> = (0..4).map(|_| AtomicU8::new(0)).collect();
+    let cells = RefWithOwner::new(buffer);
+
+    let handles = [0..2, 2..4].map(|range| {
+        let cells = cells.clone();
+        std::thread::spawn(move || {
+            for _ in 0..10 {
+                for cell in &cells.borrowed()[range.clone()] {
+                    cell.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        })
+    });
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let buffer = cells.into_owner();
+    assert_eq!(Arc::strong_count(&buffer), 1);
+    let values = buffer
+        .iter()
+        .map(|c| c.load(Ordering::Relaxed))
+        .collect::<Vec<_>>();
+    assert_eq!(values, [10, 10, 10, 10]);
+}
+
+impl<'a> borrowed_with_owner::TryDetach<'a> for Len {
+    fn try_detach(borrowed: usize) -> Result<usize, usize> {
+        Ok(borrowed)
+    }
+}
+
+#[test]
+fn try_detach() {
+    let len = RefWithOwner::new(String::from("abc")).map::<Len, _>(|s, _| s.len());
+    assert_eq!(len.try_detach().ok(), Some(3));
+
+    let word = RefWithOwner::new(String::from("abc")).map::<&'static str, _>(|s, _| &s[1..]);
+    let word = word.try_detach().err().unwrap();
+    assert_eq!(*word.borrowed(), "bc");
+}
+
+struct Words;
+
+impl<'a> BorrowWithLifetime<'a> for Words {
+    type Borrowed = Vec<&'a str>;
+}
+
+#[test]
+fn map_acc() {
+    let (words, skipped) = RefWithOwner::new(String::from("a,,b,,,c"))
+        .map::<SplitWrapper<'static>, _>(|s, _| SplitWrapper(s.split(",")))
+        .map_acc::<Words, _, _>(0, |split, skipped, _| {
+            split
+                .0
+                .filter(|segment| {
+                    if segment.is_empty() {
+                        *skipped += 1;
+                    }
+                    !segment.is_empty()
+                })
+                .collect()
+        });
+
+    assert_eq!(*words.borrowed(), ["a", "b", "c"]);
+    assert_eq!(skipped, 3);
+}
+
+struct Inventory {
+    owner: String,
+    items: Vec<i32>,
+}
+
+#[test]
+fn project_mut() {
+    use borrowed_with_owner::RefMutWithOwner;
+
+    let inventory = Box::new(Inventory {
+        owner: "me".into(),
+        items: vec![1],
+    });
+
+    let mut items = RefMutWithOwner::new(inventory).project_mut(|inv| &mut inv.items);
+    items.borrowed_mut().push(2);
+    items.borrowed_mut().push(3);
+
+    let inventory = items.into_owner();
+    assert_eq!(inventory.owner, "me");
+    assert_eq!(inventory.items, [1, 2, 3]);
+}
+
+fn split_key_value(owner: String) -> Result<RefWithOwner<String>, (String, String)> {
+    RefWithOwner::new(owner).try_map::<&'static str, _, _>(|s, _| {
+        s.split_once('=')
+            .map(|(_, value)| value)
+            .ok_or_else(|| format!("no '=' in {:?}", s))
+    })
+}
+
+#[test]
+fn try_map() {
+    let (err, mut owner) = match split_key_value("name".into()) {
+        Ok(_) => panic!("expected try_map to fail"),
+        Err(err) => err,
+    };
+    assert_eq!(err, "no '=' in \"name\"");
+
+    owner.push_str("=Michael");
+    let value = split_key_value(owner).unwrap();
+    assert_eq!(*value.borrowed(), "Michael");
+}
+
+#[test]
+fn debug() {
+    let name = RefWithOwner::new(Box::new(String::from("Ada")));
+    assert_eq!(
+        format!("{:?}", name),
+        r#"BorrowedWithOwner { borrowed: "Ada" }"#
+    );
+
+    let words =
+        RefWithOwner::new(String::from("a b")).map::<Words, _>(|s, _| s.split(' ').collect());
+    assert_eq!(
+        format!("{:?}", words),
+        r#"BorrowedWithOwner { borrowed: ["a", "b"] }"#
+    );
+}
+
+#[test]
+fn eq_ignores_owner() {
+    let a = RefWithOwner::new(std::rc::Rc::<str>::from("same"));
+    let b = RefWithOwner::new(std::rc::Rc::<str>::from("same"));
+    assert_ne!(a.owner_ptr(), b.owner_ptr());
+    assert_eq!(a, b);
+
+    let c = RefWithOwner::new(std::rc::Rc::<str>::from("same, but longer"))
+        .map::<&'static str, _>(|s, _| &s[..4]);
+    assert_eq!(a, c);
+    assert_ne!(a, RefWithOwner::new(std::rc::Rc::<str>::from("different")));
+}
+
+#[test]
+fn hash_map_key() {
+    use std::collections::HashMap;
+
+    let mut counts = HashMap::new();
+    for line in ["key=a", "key=b", "other=a"] {
+        let value = RefWithOwner::new(String::from(line))
+            .map::<&'static str, _>(|s, _| s.split_once('=').unwrap().1);
+        *counts.entry(value).or_insert(0) += 1;
+    }
+
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&RefWithOwner::new(String::from("a"))], 2);
+}
+
+#[test]
+fn map_deref() {
+    use std::sync::Arc;
+
+    let numbers = RefWithOwner::new(Arc::new(vec![1, 2, 3])).map_deref();
+    let slice: &[i32] = numbers.borrowed();
+    assert_eq!(slice, [1, 2, 3]);
+
+    let nested = RefWithOwner::new(Box::new(Box::<str>::from("nested"))).map_deref();
+    assert_eq!(*nested.borrowed(), "nested");
+}
+
+#[test]
+fn owner() {
+    use std::sync::Arc;
+
+    let body = RefWithOwner::new(Arc::new(b"header body".to_vec()))
+        .map::<&'static [u8], _>(|v, _| &v[7..]);
+    assert_eq!(*body.borrowed(), b"body");
+    assert_eq!(body.owner().len(), 11);
+    assert_eq!(Arc::strong_count(body.owner()), 1);
+}
+
+#[test]
+fn clone_rc_bundle() {
+    use std::rc::Rc;
+
+    let word =
+        RefWithOwner::new(Rc::<str>::from("hello world")).map::<&'static str, _>(|s, _| &s[6..]);
+    let copy = word.clone();
+    drop(word);
+
+    assert_eq!(*copy.borrowed(), "world");
+    assert_eq!(Rc::strong_count(copy.owner()), 1);
+}
+
+#[test]
+fn clone_arc_bundle_across_threads() {
+    use std::sync::Arc;
+
+    let words =
+        RefWithOwner::new(Arc::<str>::from("a b c")).map::<Words, _>(|s, _| s.split(' ').collect());
+    let handles = (0..3)
+        .map(|i| {
+            let words = words.clone();
+            std::thread::spawn(move || words.borrowed()[i].to_owned())
+        })
+        .collect::<Vec<_>>();
+
+    let results = handles
+        .into_iter()
+        .map(|h| h.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(results, ["a", "b", "c"]);
+    assert_eq!(Arc::strong_count(words.owner()), 1);
+}
+
+#[test]
+fn map_with_extra() {
+    let names = RefWithOwner::new(String::from("Ada Grace"));
+    let greeting = RefWithOwner::new(String::from("Hello"));
+
+    let greetings = names.map_with_extra::<Words, _, _>(greeting, |names, greeting, _| {
+        names.split(' ').flat_map(|name| [greeting, name]).collect()
+    });
+
+    assert_eq!(*greetings.borrowed(), ["Hello", "Ada", "Hello", "Grace"]);
+    let owners = greetings.into_owner();
+    assert_eq!(
+        (owners.0.as_str(), owners.1.as_str()),
+        ("Ada Grace", "Hello")
+    );
+}
+
+#[test]
+fn bundled_iterator() {
+    let chars = RefWithOwner::new(String::from("héllo")).map::<Chars, _>(|s, _| s.chars());
+
+    let filtered = std::thread::spawn(move || chars.filter(|c| *c != 'l').collect::<String>())
+        .join()
+        .unwrap();
+    assert_eq!(filtered, "héo");
+}
+
+#[test]
+fn tuple_projection() {
+    use std::str::{Chars, SplitWhitespace};
+
+    let mut parts = RefWithOwner::new(String::from("hello big world")).map::<(
+        Chars<'static>,
+        &'static [u8],
+        SplitWhitespace<'static>,
+    ), _>(|s, _| {
+        (s.chars(), s.as_bytes(), s.split_whitespace())
+    });
+
+    let (chars, bytes, words) = parts.borrowed_mut();
+    assert_eq!(chars.next(), Some('h'));
+    assert_eq!(bytes.len(), 15);
+    assert_eq!(words.nth(1), Some("big"));
+}
+
+#[test]
+fn split_once_bundled() {
+    use std::sync::Arc;
+
+    let pair = RefWithOwner::new(Arc::<str>::from("key=value"));
+    let (key, value) = pair.clone().split_once_bundled("=").unwrap();
+    drop(pair);
+    assert_eq!(&*key, "key");
+    assert_eq!(&*value, "value");
+    assert_eq!(Arc::strong_count(key.owner()), 2);
+
+    let (before, after) = value.split_once_bundled("lu").unwrap();
+    assert_eq!((&*before, &*after), ("va", "e"));
+
+    assert!(key.split_once_bundled("=").is_none());
+}
+
+#[test]
+fn option_and_result_projection() {
+    let find = |needle: &'static str| {
+        RefWithOwner::new(String::from("key=value"))
+            .map::<Option<&'static str>, _>(move |s, _| s.find(needle).map(|i| &s[i..]))
+    };
+
+    assert_eq!(*find("=").borrowed(), Some("=value"));
+    let missing = find("?");
+    assert_eq!(*missing.borrowed(), None);
+    assert_eq!(missing.into_owner(), "key=value");
+
+    let parsed = RefWithOwner::new(String::from("12x")).map::<Result<&'static str, usize>, _>(
+        |s, _| match s.find(|c: char| !c.is_ascii_digit()) {
+            Some(i) => Err(i),
+            None => Ok(s),
+        },
+    );
+    assert_eq!(*parsed.borrowed(), Err(2));
+}
+
+#[test]
+fn map_opt() {
+    let find = |haystack: &str, needle: &'static str| {
+        RefWithOwner::new(String::from(haystack))
+            .map_opt::<&'static str, _>(|s, _| s.find(needle).map(|i| &s[i..i + needle.len()]))
+    };
+
+    let found = find("hello world", "wor").unwrap();
+    assert_eq!(&*found, "wor");
+
+    let owner = find("hello world", "xyz").unwrap_err();
+    assert_eq!(owner, "hello world");
+}
+
+#[test]
+fn map_split() {
+    use std::sync::Arc;
+
+    let packet = RefWithOwner::new(Arc::new(b"HDR:payload".to_vec()));
+    let (header, body) =
+        packet.map_split::<&'static [u8], &'static [u8], _>(|bytes, _| bytes.split_at(4));
+    assert_eq!(Arc::strong_count(header.owner()), 2);
+
+    let header = std::thread::spawn(move || header.to_vec()).join().unwrap();
+    assert_eq!(header, b"HDR:");
+    assert_eq!(*body, *b"payload");
+    assert_eq!(Arc::strong_count(body.owner()), 1);
+}
+
+#[test]
+fn zip() {
+    use borrowed_with_owner::OwnerPair;
+    use std::sync::Arc;
+
+    let left = RefWithOwner::new(String::from("left side")).map::<&'static str, _>(|s, _| &s[..4]);
+    let right = RefWithOwner::new(Arc::new(String::from("right side")));
+
+    let zipped = borrowed_with_owner::zip(left, right);
+    let (left, right) = zipped.borrowed();
+    assert_eq!(*left, "left");
+    assert_eq!(right.as_str(), "right side");
+
+    let zipped = std::thread::spawn(move || zipped).join().unwrap();
+    let OwnerPair(left, right) = zipped.into_owner();
+    assert_eq!(left, "left side");
+    assert_eq!(Arc::strong_count(&right), 1);
+}
+
+#[test]
+fn iterator_filter_map_is_not_shadowed() {
+    let digits = RefWithOwner::new(String::from("a1b2c3"))
+        .map::<std::str::Chars<'static>, _>(|s, _| s.chars())
+        .filter_map(|c| c.to_digit(10))
+        .collect::<Vec<_>>();
+    assert_eq!(digits, [1, 2, 3]);
+}
+
+#[test]
+fn iterator_zip_is_not_shadowed() {
+    let indexed = RefWithOwner::new(String::from("ab"))
+        .map::<std::str::Chars<'static>, _>(|s, _| s.chars())
+        .zip(0..)
+        .collect::<Vec<_>>();
+    assert_eq!(indexed, [('a', 0), ('b', 1)]);
+}
+
+#[test]
+fn lifetime_witness_is_copy_and_debug() {
+    use borrowed_with_owner::Lifetime;
+
+    fn first<'a>(s: &'a str, _: Lifetime<'a>) -> &'a str {
+        &s[..1]
+    }
+
+    fn last<'a>(s: &'a str, _: Lifetime<'a>) -> &'a str {
+        &s[s.len() - 1..]
+    }
+
+    let ends = RefWithOwner::new(String::from("abc")).map::<(&'static str, &'static str), _>(
+        |s, lifetime| {
+            assert!(format!("{:?}", lifetime).starts_with("Lifetime"));
+            (first(s, lifetime), last(s, lifetime))
+        },
+    );
+    assert_eq!(*ends.borrowed(), ("a", "c"));
+}
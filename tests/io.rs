@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+
+use borrowed_with_owner::RefWithOwner;
+
+#[test]
+fn lines_with_owner() {
+    let mut lines = RefWithOwner::new(b"first\nsecond\nthird".to_vec()).lines_with_owner();
+
+    std::thread::spawn(move || {
+        let lines = lines
+            .borrowed_mut()
+            .collect::<std::io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(lines, ["first", "second", "third"]);
+    })
+    .join()
+    .unwrap();
+}
+
+struct SliceCursor;
+
+impl<'a> borrowed_with_owner::BorrowWithLifetime<'a> for SliceCursor {
+    type Borrowed = std::io::Cursor<&'a [u8]>;
+}
+
+#[test]
+fn read_bundled_cursor() {
+    use std::io::{BufRead, Read};
+
+    let mut cursor = RefWithOwner::new(b"header\nbody bytes".to_vec())
+        .map::<SliceCursor, _>(|bytes, _| std::io::Cursor::new(bytes));
+
+    let mut header = String::new();
+    cursor.read_line(&mut header).unwrap();
+    assert_eq!(header, "header\n");
+
+    let rest = std::thread::spawn(move || {
+        let mut rest = Vec::new();
+        cursor.read_to_end(&mut rest).unwrap();
+        rest
+    })
+    .join()
+    .unwrap();
+    assert_eq!(rest, b"body bytes");
+}
+
+#[tokio::test]
+async fn write_bundled_buffer() {
+    use borrowed_with_owner::RefMutWithOwner;
+    use std::io::Write;
+
+    let mut scratch = RefMutWithOwner::new(Box::new(Vec::<u8>::new()));
+    write!(scratch, "x = {}", 1).unwrap();
+    tokio::task::yield_now().await;
+    scratch.write_all(b", y = 2").unwrap();
+    scratch.flush().unwrap();
+
+    assert_eq!(*scratch.into_owner(), b"x = 1, y = 2");
+}
@@ -0,0 +1,28 @@
+//! Run with `cargo test -p borrowed_with_owner --no-default-features --features alloc` to check
+//! that the crate works without `std`
+
+#![cfg(not(feature = "std"))]
+
+use borrowed_with_owner::{BorrowWithLifetime, RefMutWithOwner, RefWithOwner};
+
+struct Fields;
+
+impl<'a> BorrowWithLifetime<'a> for Fields {
+    type Borrowed = core::str::Split<'a, char>;
+}
+
+#[test]
+fn split_boxed_str() {
+    let mut fields =
+        RefWithOwner::new(Box::<str>::from("a,b,c")).map::<Fields, _>(|s, _| s.split(','));
+    assert_eq!(fields.borrowed_mut().nth(1), Some("b"));
+    assert_eq!(&*fields.into_owner(), "a,b,c");
+}
+
+#[test]
+fn parse_into_buffer() {
+    let mut buffer = RefMutWithOwner::new(vec![0u8; 4]);
+    *buffer.split_first_mut_borrowed().unwrap() = 1;
+    assert_eq!(buffer.borrowed().len(), 3);
+    assert_eq!(buffer.into_owner(), [1, 0, 0, 0]);
+}
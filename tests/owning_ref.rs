@@ -0,0 +1,25 @@
+#![cfg(feature = "owning_ref")]
+
+use borrowed_with_owner::{BorrowedWithOwner, RefWithOwner};
+use owning_ref::OwningRef;
+
+#[test]
+fn from_owning_ref() {
+    let owning_ref = OwningRef::new(Box::new(String::from("hello world"))).map(|s| &s[6..]);
+    // the `OwningRef` was created with `map`, which is sound
+    let bundle = unsafe { BorrowedWithOwner::from_owning_ref(owning_ref) };
+
+    let world = std::thread::spawn(move || bundle.borrowed().to_uppercase())
+        .join()
+        .unwrap();
+    assert_eq!(world, "WORLD");
+}
+
+#[test]
+fn into_owning_ref() {
+    let bundle = RefWithOwner::new(vec![1, 2, 3]).map::<&'static [i32], _>(|v, _| &v[1..]);
+    let owning_ref = bundle.into_owning_ref();
+
+    assert_eq!(*owning_ref, [2, 3]);
+    assert_eq!(*owning_ref.into_owner(), [1, 2, 3]);
+}
@@ -0,0 +1,6 @@
+use borrowed_with_owner::RefWithOwner;
+
+fn main() {
+    let bytes = RefWithOwner::new_max_size::<64>(vec![1u8, 2, 3]);
+    assert_eq!(*bytes.borrowed(), [1, 2, 3]);
+}
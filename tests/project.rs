@@ -0,0 +1,45 @@
+use borrowed_with_owner::{project, RefMutWithOwner, RefWithOwner};
+
+struct Outer {
+    inner: Inner,
+    pair: (u8, Inner),
+}
+
+struct Inner {
+    values: Vec<i32>,
+}
+
+fn outer() -> Box<Outer> {
+    Box::new(Outer {
+        inner: Inner {
+            values: vec![1, 2, 3],
+        },
+        pair: (0, Inner { values: vec![4, 5] }),
+    })
+}
+
+#[test]
+fn project_ref() {
+    let bundle = RefWithOwner::new(outer());
+    let values = project!(bundle.inner.values);
+    assert_eq!(**values, [1, 2, 3]);
+
+    let bundle = RefWithOwner::new(values.into_owner());
+    let values = project!(bundle.pair.1);
+    assert_eq!(values.values, [4, 5]);
+}
+
+#[test]
+fn project_mut() {
+    let bundle = RefMutWithOwner::new(outer());
+    let mut values = project!(mut bundle.inner.values);
+    values.borrowed_mut().push(4);
+
+    let bundle = RefMutWithOwner::new(values.into_owner());
+    let mut values = project!(mut bundle.pair.1.values);
+    values.borrowed_mut().clear();
+
+    let outer = values.into_owner();
+    assert_eq!(outer.inner.values, [1, 2, 3, 4]);
+    assert!(outer.pair.1.values.is_empty());
+}
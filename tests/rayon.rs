@@ -0,0 +1,29 @@
+#![cfg(feature = "rayon")]
+
+use borrowed_with_owner::RefWithOwner;
+
+#[test]
+fn spawn_on_sums_slice() {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(2)
+        .build()
+        .unwrap();
+    let numbers = RefWithOwner::new(vec![1, 2, 3, 4]).map::<&'static [i32], _>(|v, _| &v[1..]);
+
+    let sum = numbers.spawn_on(&pool, |slice| {
+        assert!(rayon::current_thread_index().is_some());
+        slice.iter().sum::<i32>()
+    });
+    assert_eq!(sum, 9);
+}
+
+#[test]
+fn with_par_iter_sums_slice() {
+    use rayon::prelude::*;
+
+    let numbers = RefWithOwner::new((1..=1000).collect::<Vec<u64>>());
+
+    let sum = numbers.with_par_iter(|iter| iter.map(|n| n * 2).sum::<u64>());
+    assert_eq!(sum, 1000 * 1001);
+    assert_eq!(numbers.len(), 1000);
+}
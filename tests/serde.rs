@@ -0,0 +1,103 @@
+#![cfg(feature = "serde")]
+
+use borrowed_with_owner::BytesBundle;
+
+#[test]
+fn deserialize_bytes_from_seq() {
+    let json = serde_json::to_string(&[1u8, 2, 3, 255]).unwrap();
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let bytes = BytesBundle::deserialize_bytes(&mut deserializer).unwrap();
+
+    assert_eq!(*bytes.borrowed(), [1, 2, 3, 255]);
+    assert_eq!(serde_json::to_string(bytes.borrowed()).unwrap(), json);
+}
+
+#[test]
+fn base64_round_trip() {
+    use borrowed_with_owner::RefWithOwner;
+
+    let bytes = RefWithOwner::new(vec![1u8, 2, 3, 255]);
+    let mut json = Vec::new();
+    bytes
+        .serialize_bytes(&mut serde_json::Serializer::new(&mut json))
+        .unwrap();
+    assert_eq!(json, br#""AQID/w==""#);
+
+    let mut deserializer = serde_json::Deserializer::from_slice(&json);
+    let round_tripped = BytesBundle::deserialize_bytes(&mut deserializer).unwrap();
+    assert_eq!(round_tripped, bytes);
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#""not base64!""#);
+    assert!(BytesBundle::deserialize_bytes(&mut deserializer).is_err());
+}
+
+#[test]
+fn deserialize_bytes_from_byte_buf() {
+    use serde::de::value::{BytesDeserializer, Error};
+
+    let deserializer = BytesDeserializer::<Error>::new(b"raw bytes");
+    let bytes = BytesBundle::deserialize_bytes(deserializer).unwrap();
+
+    assert_eq!(*bytes.borrowed(), b"raw bytes");
+}
+
+/// A sequence that claims to be huge, like a length prefix read from untrusted input, but ends
+/// after a few bytes
+struct TruncatedSeq(u8);
+
+impl<'de> serde::de::SeqAccess<'de> for TruncatedSeq {
+    type Error = serde::de::value::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: serde::de::DeserializeSeed<'de>,
+    {
+        use serde::de::{Error, IntoDeserializer};
+
+        if self.0 == 0 {
+            return Err(Self::Error::custom("unexpected end of input"));
+        }
+        self.0 -= 1;
+        seed.deserialize(self.0.into_deserializer()).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(usize::MAX)
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for TruncatedSeq {
+    type Error = serde::de::value::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_seq(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum
+        identifier ignored_any
+    }
+}
+
+#[test]
+fn deserialize_bytes_with_huge_declared_length() {
+    let err = BytesBundle::deserialize_bytes(TruncatedSeq(3)).unwrap_err();
+    assert_eq!(err.to_string(), "unexpected end of input");
+}
+
+#[test]
+fn serialize_projection() {
+    use borrowed_with_owner::RefWithOwner;
+
+    let name = RefWithOwner::new(String::from("name=Ada"))
+        .map::<&'static str, _>(|s, _| s.split_once('=').unwrap().1);
+    let json = serde_json::to_string(&name).unwrap();
+    assert_eq!(json, r#""Ada""#);
+
+    let round_tripped = RefWithOwner::new(serde_json::from_str::<String>(&json).unwrap());
+    assert_eq!(round_tripped, name);
+}
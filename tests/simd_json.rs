@@ -0,0 +1,27 @@
+#![cfg(feature = "simd-json")]
+
+use borrowed_with_owner::JsonBundle;
+use simd_json::prelude::*;
+
+#[test]
+fn read_field_on_another_thread() {
+    let buffer = br#"{"name": "Michael", "tags": ["a", "b"]}"#.to_vec();
+    let json = JsonBundle::parse(buffer).unwrap();
+
+    std::thread::spawn(move || {
+        let value = json.borrowed();
+        assert_eq!(value.get_str("name"), Some("Michael"));
+        assert_eq!(value.get_array("tags").map(|tags| tags.len()), Some(2));
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn parse_error_returns_buffer() {
+    let (_, buffer) = match JsonBundle::parse(b"{not json".to_vec()) {
+        Ok(_) => panic!("expected a parse error"),
+        Err(err) => err,
+    };
+    assert_eq!(buffer.len(), 9);
+}
@@ -0,0 +1,219 @@
+use borrowed_with_owner::RefWithOwner;
+use std::slice::Split;
+
+#[test]
+fn bundled_slice_split() {
+    let mut segments =
+        RefWithOwner::new(b"one\ntwo\nthree".to_vec())
+            .map::<Split<'static, u8, fn(&u8) -> bool>, _>(|bytes, _| bytes.split(|&b| b == b'\n'));
+
+    std::thread::spawn(move || {
+        let segments = segments.borrowed_mut().collect::<Vec<_>>();
+        assert_eq!(segments, [&b"one"[..], b"two", b"three"]);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn subslice() {
+    let numbers: Box<[i32]> = vec![0, 1, 2, 3, 4, 5, 6].into_boxed_slice();
+    let view = RefWithOwner::new(numbers).subslice(2..5);
+    assert_eq!(*view.borrowed(), [2, 3, 4]);
+
+    let view = view.subslice(1..3);
+    assert_eq!(*view.borrowed(), [3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "subslice range 2..5 out of bounds for slice of length 3")]
+fn subslice_out_of_bounds() {
+    RefWithOwner::new(vec![1, 2, 3]).subslice(2..5);
+}
+
+#[test]
+fn binary_search_range() {
+    let numbers: Box<[i32]> = vec![1, 3, 5, 5, 8, 13].into_boxed_slice();
+    let view = RefWithOwner::new(numbers).binary_search_range(|n| *n < 5);
+    assert_eq!(*view.borrowed(), [5, 5, 8, 13]);
+
+    let view = view.binary_search_range(|n| *n < 100);
+    assert!(view.borrowed().is_empty());
+}
+
+#[test]
+fn with_enumerate() {
+    let numbers = RefWithOwner::new(vec![5, 6, 7]);
+    let weighted = numbers.with_enumerate(|iter| iter.map(|(i, n)| i as i32 * n).sum::<i32>());
+    assert_eq!(weighted, 6 + 14);
+}
+
+#[test]
+fn with_windows() {
+    let numbers = RefWithOwner::new(vec![1, -2, 5, 4, -1, 3]);
+    let max_sum = numbers.with_windows(3, |windows| windows.map(|w| w.iter().sum::<i32>()).max());
+    assert_eq!(max_sum, Some(8));
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn with_windows_zero() {
+    RefWithOwner::new(vec![1, 2]).with_windows(0, |windows| windows.count());
+}
+
+#[test]
+fn with_rotated() {
+    let buffer = RefWithOwner::new(vec![1, 2, 3, 4, 5]).map::<&'static [i32], _>(|v, _| &v[1..]);
+    let rotated = buffer.with_rotated(1, |iter| iter.copied().collect::<Vec<_>>());
+    assert_eq!(rotated, [3, 4, 5, 2]);
+    assert_eq!(buffer.with_rotated(0, |iter| iter.count()), 4);
+    assert_eq!(buffer.with_rotated(4, |iter| iter.copied().next()), Some(2));
+    assert_eq!(*buffer, [2, 3, 4, 5]);
+}
+
+struct CopiedIter;
+
+impl<'a> borrowed_with_owner::BorrowWithLifetime<'a> for CopiedIter {
+    type Borrowed = std::iter::Copied<std::slice::Iter<'a, i32>>;
+}
+
+#[test]
+fn fold_consume() {
+    let iter = RefWithOwner::new(Box::new(vec![1, 2, 3, 4]))
+        .map::<CopiedIter, _>(|v, _| v.iter().copied());
+
+    let (sum, owner) = iter.fold_consume(0, |acc, x| acc + x);
+    assert_eq!(sum, 10);
+    assert_eq!(*owner, [1, 2, 3, 4]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write_cursor() {
+    use borrowed_with_owner::RefMutWithOwner;
+
+    let mut cursor = RefMutWithOwner::new(vec![0u8; 8]);
+
+    *cursor.split_first_mut_borrowed().unwrap() = 0xaa;
+    cursor.write_u16_be_borrowed(0x0102).unwrap();
+    cursor.write_u32_be_borrowed(0x03040506).unwrap();
+    assert_eq!(cursor.borrowed().len(), 1);
+
+    let err = cursor.write_u16_be_borrowed(0xffff).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+
+    assert_eq!(cursor.into_owner(), [0xaa, 1, 2, 3, 4, 5, 6, 0]);
+}
+
+#[test]
+fn chunks_exact_remainder() {
+    use std::slice::ChunksExact;
+
+    let samples: Box<[f32]> = (0..10).map(|i| i as f32).collect();
+    let mut chunks = RefWithOwner::new(samples)
+        .map::<ChunksExact<'static, f32>, _>(|samples, _| samples.chunks_exact(4));
+
+    let sums = chunks
+        .borrowed_mut()
+        .map(|chunk| chunk.iter().sum::<f32>())
+        .collect::<Vec<_>>();
+    assert_eq!(sums, [6.0, 22.0]);
+    assert_eq!(chunks.remainder_borrowed(), [8.0, 9.0]);
+}
+
+#[test]
+fn windows_on_another_thread() {
+    use std::slice::Windows;
+
+    let mut windows = RefWithOwner::new(vec![1u32, 4, 9, 16])
+        .map::<Windows<'static, u32>, _>(|values, _| values.windows(2));
+
+    let deltas = std::thread::spawn(move || {
+        windows
+            .borrowed_mut()
+            .map(|pair| pair[1] - pair[0])
+            .collect::<Vec<_>>()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(deltas, [3, 5, 7]);
+}
+
+#[test]
+fn iter_chunks_and_iter_mut() {
+    use borrowed_with_owner::RefMutWithOwner;
+    use std::slice::{Chunks, Iter, IterMut};
+
+    let mut iter =
+        RefWithOwner::new(vec![1u32, 2, 3]).map::<Iter<'static, u32>, _>(|v, _| v.iter());
+    assert_eq!(iter.borrowed_mut().next_back(), Some(&3));
+    assert_eq!(iter.borrowed().as_slice(), [1, 2]);
+
+    let mut chunks =
+        RefWithOwner::new(iter.into_owner()).map::<Chunks<'static, u32>, _>(|v, _| v.chunks(2));
+    assert_eq!(chunks.borrowed_mut().last(), Some(&[3][..]));
+
+    let mut iter_mut =
+        RefMutWithOwner::new(vec![1u32, 2, 3]).map::<IterMut<'static, u32>, _>(|v, _| v.iter_mut());
+    iter_mut.borrowed_mut().for_each(|value| *value *= 10);
+    assert_eq!(iter_mut.into_owner(), [10, 20, 30]);
+}
+
+#[test]
+fn dedup_to_owned() {
+    let sorted =
+        RefWithOwner::new(vec![1, 1, 2, 3, 3, 3, 5, 8, 8]).map::<&'static [i32], _>(|v, _| &v[1..]);
+    assert_eq!(sorted.dedup_to_owned(), [1, 2, 3, 5, 8]);
+
+    let empty = RefWithOwner::new(Vec::<i32>::new());
+    assert!(empty.dedup_to_owned().is_empty());
+}
+
+#[test]
+fn partition_at() {
+    use std::sync::Arc;
+
+    let numbers = RefWithOwner::new(Arc::<[i32]>::from([1, 2, 3, 4, 5]));
+    let (front, back) = numbers.partition_at(3);
+
+    let back = std::thread::spawn(move || back.iter().sum::<i32>())
+        .join()
+        .unwrap();
+    assert_eq!(*front, [1, 2, 3]);
+    assert_eq!(back, 9);
+
+    let (empty, all) = front.partition_at(0);
+    assert!(empty.is_empty());
+    assert_eq!(*all, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "mid 4 out of bounds for slice of length 3")]
+fn partition_at_out_of_bounds() {
+    let numbers = RefWithOwner::new(std::rc::Rc::new([1, 2, 3]));
+    numbers.map::<&'static [i32], _>(|a, _| a).partition_at(4);
+}
+
+struct CopiedU32s;
+
+impl<'a> borrowed_with_owner::BorrowWithLifetime<'a> for CopiedU32s {
+    type Borrowed = std::iter::Copied<std::slice::Iter<'a, u32>>;
+}
+
+#[test]
+fn double_ended_exact_size() {
+    let numbers =
+        || RefWithOwner::new(vec![1u32, 2, 3, 4]).map::<CopiedU32s, _>(|v, _| v.iter().copied());
+
+    let mut iter = numbers();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.len(), 3);
+
+    assert_eq!(numbers().rev().collect::<Vec<_>>(), [4, 3, 2, 1]);
+    assert_eq!(numbers().rposition(|n| n % 2 == 1), Some(2));
+
+    let mut fused = numbers().fuse();
+    assert_eq!(fused.by_ref().count(), 4);
+    assert_eq!(fused.next(), None);
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "smallvec")]
+
+use borrowed_with_owner::{BorrowWithLifetime, RefMutWithOwner, RefWithOwner};
+use smallvec::{smallvec, SmallVec};
+
+struct SliceIter;
+
+impl<'a> BorrowWithLifetime<'a> for SliceIter {
+    type Borrowed = std::slice::Iter<'a, i32>;
+}
+
+#[test]
+fn bundled_small_vec_iter() {
+    let v: Box<SmallVec<[i32; 4]>> = Box::new(smallvec![1, 2, 3, 4, 5]);
+    let mut iter = RefWithOwner::new(v).map::<SliceIter, _>(|v, _| v.iter());
+
+    let collected = std::thread::spawn(move || iter.borrowed_mut().copied().collect::<Vec<_>>())
+        .join()
+        .unwrap();
+    assert_eq!(collected, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn bundled_small_vec_drain() {
+    let v: Box<SmallVec<[i32; 4]>> = Box::new(smallvec![1, 2, 3]);
+    let mut drain = RefMutWithOwner::new(v).drain_with_owner();
+
+    assert_eq!(drain.borrowed_mut().collect::<Vec<_>>(), [1, 2, 3]);
+    assert!(drain.into_owner().is_empty());
+}
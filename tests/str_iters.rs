@@ -0,0 +1,72 @@
+use borrowed_with_owner::{
+    BytesWithOwner, CharIndicesWithOwner, CharsWithOwner, LinesWithOwner, RefWithOwner,
+    SplitAsciiWhitespaceWithOwner, SplitWhitespaceWithOwner,
+};
+use std::str::{EncodeUtf16, Split};
+
+fn owned() -> RefWithOwner<String> {
+    RefWithOwner::new(String::from("héllo wörld\nsecond  line"))
+}
+
+#[test]
+fn chars() {
+    let mut chars: CharsWithOwner<String> = owned().map(|s, _| s.chars());
+    assert_eq!(chars.borrowed_mut().nth(1), Some('é'));
+    assert_eq!(chars.borrowed().as_str().len(), 23);
+}
+
+#[test]
+fn char_indices() {
+    let mut indices: CharIndicesWithOwner<String> = owned().map(|s, _| s.char_indices());
+    assert_eq!(indices.borrowed_mut().nth(2), Some((3, 'l')));
+}
+
+#[test]
+fn bytes() {
+    let mut bytes: BytesWithOwner<String> = owned().map(|s, _| s.bytes());
+    assert_eq!(bytes.borrowed_mut().next(), Some(b'h'));
+    assert_eq!(bytes.borrowed().len(), 25);
+}
+
+#[test]
+fn lines() {
+    let mut lines: LinesWithOwner<String> = owned().map(|s, _| s.lines());
+    assert_eq!(
+        lines.borrowed_mut().collect::<Vec<_>>(),
+        ["héllo wörld", "second  line"]
+    );
+}
+
+#[test]
+fn split_whitespace() {
+    let mut words: SplitWhitespaceWithOwner<String> = owned().map(|s, _| s.split_whitespace());
+    assert_eq!(words.borrowed_mut().count(), 4);
+
+    let mut words: SplitAsciiWhitespaceWithOwner<String> =
+        owned().map(|s, _| s.split_ascii_whitespace());
+    assert_eq!(words.borrowed_mut().last(), Some("line"));
+}
+
+#[test]
+fn encode_utf16() {
+    let mut units = owned().map::<EncodeUtf16<'static>, _>(|s, _| s.encode_utf16());
+    assert_eq!(units.borrowed_mut().count(), 24);
+}
+
+#[test]
+fn split() {
+    let mut by_char = owned().map::<Split<'static, char>, _>(|s, _| s.split('\n'));
+    assert_eq!(by_char.borrowed_mut().next(), Some("héllo wörld"));
+
+    let mut by_str = owned().map::<Split<'static, &'static str>, _>(|s, _| s.split("  "));
+    assert_eq!(by_str.borrowed_mut().last(), Some("line"));
+}
+
+#[test]
+fn sent_to_thread() {
+    let mut chars = owned().map::<std::str::Chars<'static>, _>(|s, _| s.chars());
+    let last = std::thread::spawn(move || chars.borrowed_mut().last())
+        .join()
+        .unwrap();
+    assert_eq!(last, Some('e'));
+}
@@ -0,0 +1,47 @@
+//! Checks that panics are reported at the caller's location rather than inside the crate. This is
+//! a separate test binary, with a single test, because it installs a panic hook.
+
+use borrowed_with_owner::RefWithOwner;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+
+static LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+fn panic_location(f: impl FnOnce()) -> (String, u32) {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+    LOCATION.lock().unwrap().take().unwrap()
+}
+
+#[test]
+fn panics_report_caller_location() {
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().unwrap();
+        *LOCATION.lock().unwrap() = Some((location.file().to_owned(), location.line()));
+    }));
+
+    let numbers = || RefWithOwner::new(vec![1, 2, 3]);
+
+    let line = line!() + 1;
+    let location = panic_location(|| drop(numbers().subslice(1..4)));
+    assert_eq!(location, (file!().to_owned(), line));
+
+    let line = line!() + 1;
+    let location = panic_location(|| numbers().with_windows(0, |_| ()));
+    assert_eq!(location, (file!().to_owned(), line));
+
+    let line = line!() + 1;
+    let location = panic_location(|| numbers().with_rotated(4, |_| ()));
+    assert_eq!(location, (file!().to_owned(), line));
+
+    let shared = RefWithOwner::new(std::rc::Rc::new([1, 2, 3])).map::<&'static [i32], _>(|a, _| a);
+    let line = line!() + 1;
+    let location = panic_location(|| drop(shared.partition_at(4)));
+    assert_eq!(location, (file!().to_owned(), line));
+
+    // panics in the closure passed to `map` are reported where the closure is written
+    let line = line!() + 1;
+    let location = panic_location(|| drop(numbers().map::<&'static [i32], _>(|_, _| panic!())));
+    assert_eq!(location, (file!().to_owned(), line));
+
+    let _ = panic::take_hook();
+}
@@ -0,0 +1,14 @@
+use borrowed_with_owner::{BorrowWithLifetime, RefWithOwner};
+
+struct SliceIter;
+
+impl<'a> BorrowWithLifetime<'a> for SliceIter {
+    type Borrowed = std::slice::Iter<'a, i32>;
+}
+
+fn main() {
+    let iter = RefWithOwner::new(vec![1, 2, 3]).map::<SliceIter, _>(|v, _| v.iter());
+    // the items borrow from the owner, so they can't outlive the bundle
+    let first: Option<&'static i32> = iter.into_iter().next();
+    drop(first);
+}
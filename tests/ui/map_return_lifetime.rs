@@ -0,0 +1,7 @@
+use borrowed_with_owner::RefWithOwner;
+
+fn main() {
+    // the second argument to the closure is just a lifetime witness,
+    // and can't be returned in place of a borrow of the owner
+    let _ = RefWithOwner::new(String::from("foo")).map::<&'static (), _>(|_, lifetime| lifetime);
+}
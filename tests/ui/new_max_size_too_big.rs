@@ -0,0 +1,5 @@
+use borrowed_with_owner::RefWithOwner;
+
+fn main() {
+    let _ = RefWithOwner::new_max_size::<8>(vec![1u8, 2, 3]);
+}
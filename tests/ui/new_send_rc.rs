@@ -0,0 +1,6 @@
+use borrowed_with_owner::RefWithOwner;
+use std::rc::Rc;
+
+fn main() {
+    let _ = RefWithOwner::new_send(Rc::new(String::from("not send")));
+}
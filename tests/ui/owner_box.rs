@@ -0,0 +1,9 @@
+use borrowed_with_owner::RefMutWithOwner;
+use std::cell::RefCell;
+
+fn main() {
+    let mut s = RefMutWithOwner::new(Box::new(RefCell::new(String::from("foo"))))
+        .map::<&'static mut String, _>(|cell, _| cell.get_mut());
+    let _alias = s.owner().borrow();
+    s.borrowed_mut().push('!');
+}
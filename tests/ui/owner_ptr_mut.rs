@@ -0,0 +1,8 @@
+use borrowed_with_owner::RefMutWithOwner;
+
+fn main() {
+    let mut v = RefMutWithOwner::new(vec![1, 2, 3]);
+    v.borrowed_mut()[0] = 4;
+    let _ptr = v.owner_ptr();
+    v.borrowed_mut()[0] = 5;
+}
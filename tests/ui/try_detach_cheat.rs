@@ -0,0 +1,16 @@
+use borrowed_with_owner::{BorrowWithLifetime, TryDetach};
+
+struct Word;
+
+impl<'a> BorrowWithLifetime<'a> for Word {
+    type Borrowed = &'a str;
+}
+
+// claiming that a borrowed `&'a str` doesn't borrow from the owner is rejected
+impl<'a> TryDetach<'a> for Word {
+    fn try_detach(borrowed: &'a str) -> Result<&'static str, &'a str> {
+        Ok(borrowed)
+    }
+}
+
+fn main() {}
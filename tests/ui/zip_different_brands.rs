@@ -0,0 +1,9 @@
+use borrowed_with_owner::RefWithOwner;
+use std::sync::Arc;
+
+fn main() {
+    let a = RefWithOwner::new(Arc::<str>::from("a"));
+    let b = RefWithOwner::new(Arc::<str>::from("b"));
+
+    a.branded(|a| b.branded(|b| a.zip(b).into_inner()));
+}